@@ -0,0 +1,348 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::fallible::check_range;
+use crate::{DebugPlaceholder, EveryRangeIter, RemainingRanges};
+
+/// `EveryRangeBuilder` configures an [`EveryRangeIter`] before
+/// it is constructed, instead of combining the various
+/// `every_range_*` methods on [`EveryRange`].
+///
+/// This `struct` is created by the [`every_range_builder`] function.
+///
+/// [`every_range_builder`]: fn.every_range_builder.html
+/// [`EveryRange`]: trait.EveryRange.html
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+///
+/// # Example
+///
+/// ```
+/// use every_range::{every_range_builder, EveryRangeKind::*};
+///
+/// let items = every_range_builder()
+///     .start(1)
+///     .end(8)
+///     .skip_trailing_gap(true)
+///     .build(vec![2..4, 5..6].into_iter())
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(
+///     items,
+///     [(Excluded, 1..2), (Included, 2..4), (Excluded, 4..5), (Included, 5..6)]
+/// );
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug)]
+pub struct EveryRangeBuilder {
+    start: usize,
+    end: usize,
+    skip_empty_gaps: bool,
+    skip_leading_gap: bool,
+    skip_trailing_gap: bool,
+    lenient: bool,
+}
+
+/// Create an [`EveryRangeBuilder`], with `start` and `end` being `0`
+/// and all options disabled.
+///
+/// [`EveryRangeBuilder`]: struct.EveryRangeBuilder.html
+#[inline]
+pub fn every_range_builder() -> EveryRangeBuilder {
+    EveryRangeBuilder::default()
+}
+
+impl EveryRangeBuilder {
+    /// Set the index of the first item, the same
+    /// as [`every_range_from`]. Defaults to `0`.
+    ///
+    /// [`every_range_from`]: trait.EveryRange.html#method.every_range_from
+    #[inline]
+    pub fn start(mut self, start: usize) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Set the "end point", the same as [`every_range`]. Defaults to `0`.
+    ///
+    /// [`every_range`]: trait.EveryRange.html#method.every_range
+    #[inline]
+    pub fn end(mut self, end: usize) -> Self {
+        self.end = end;
+        self
+    }
+
+    /// Skip all zero-length input ranges, e.g. `4..4`.
+    ///
+    /// *Note that unlike [`every_range_non_empty`], the skipped ranges
+    /// are removed before the gaps are generated. Thereby, a zero-length
+    /// range never splits the gap surrounding it.*
+    ///
+    /// [`every_range_non_empty`]: trait.EveryRange.html#method.every_range_non_empty
+    #[inline]
+    pub fn skip_empty_gaps(mut self, skip: bool) -> Self {
+        self.skip_empty_gaps = skip;
+        self
+    }
+
+    /// Suppress the leading [`Excluded`] range, the same
+    /// as [`skip_leading`].
+    ///
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    /// [`skip_leading`]: struct.EveryRangeIter.html#method.skip_leading
+    #[inline]
+    pub fn skip_leading_gap(mut self, skip: bool) -> Self {
+        self.skip_leading_gap = skip;
+        self
+    }
+
+    /// Suppress the trailing [`Excluded`] range, the same
+    /// as [`skip_trailing`].
+    ///
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    /// [`skip_trailing`]: struct.EveryRangeIter.html#method.skip_trailing
+    #[inline]
+    pub fn skip_trailing_gap(mut self, skip: bool) -> Self {
+        self.skip_trailing_gap = skip;
+        self
+    }
+
+    /// Clamp or skip invalid ranges instead of panicking, the same
+    /// as [`every_range_clamped`]. Additionally, ranges starting
+    /// before `start` are skipped.
+    ///
+    /// [`every_range_clamped`]: trait.EveryRange.html#method.every_range_clamped
+    #[inline]
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Create an [`EveryRangeIter`] over `iter` with the configured options.
+    ///
+    /// [`EveryRangeIter`]: struct.EveryRangeIter.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is greater than `end`.
+    pub fn build<I>(self, iter: I) -> EveryRangeIter<BuiltRanges<I>>
+    where
+        I: Iterator<Item = Range<usize>>,
+    {
+        assert!(self.start <= self.end);
+
+        let mut iter = EveryRangeIter::with_index(iter, self.start, self.end);
+
+        if self.skip_trailing_gap {
+            iter = iter.skip_trailing();
+        }
+
+        let mut iter = iter.rebuild(|ranges| BuiltRanges {
+            iter: ranges,
+            prev_end: self.start,
+            end: self.end,
+            skip_empty: self.skip_empty_gaps,
+            lenient: self.lenient,
+        });
+
+        if self.skip_leading_gap {
+            iter = iter.skip_leading();
+        }
+
+        iter
+    }
+}
+
+/// `BuiltRanges` is the inner iterator of an [`EveryRangeIter`]
+/// created by [`EveryRangeBuilder`], which skips or clamps
+/// the input ranges as configured.
+///
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`EveryRangeBuilder`]: struct.EveryRangeBuilder.html
+pub struct BuiltRanges<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: RemainingRanges<I>,
+    prev_end: usize,
+    end: usize,
+    skip_empty: bool,
+    lenient: bool,
+}
+
+impl<I> fmt::Debug for BuiltRanges<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BuiltRanges")
+            .field("iter", &DebugPlaceholder("<iterator>"))
+            .field("prev_end", &self.prev_end)
+            .field("end", &self.end)
+            .field("skip_empty", &self.skip_empty)
+            .field("lenient", &self.lenient)
+            .finish()
+    }
+}
+
+impl<I> Iterator for BuiltRanges<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for range in &mut self.iter {
+            let range = if self.lenient {
+                range.start..range.end.min(self.end)
+            } else {
+                range
+            };
+
+            if self.skip_empty && (range.start == range.end) {
+                continue;
+            }
+
+            if !self.lenient {
+                return Some(range);
+            }
+
+            if check_range(self.prev_end, self.end, &range).is_ok() {
+                self.prev_end = range.end;
+                return Some(range);
+            }
+        }
+
+        None
+    }
+}
+
+impl<I> FusedIterator for BuiltRanges<I> where I: Iterator<Item = Range<usize>> + FusedIterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    fn ranges() -> impl Iterator<Item = Range<usize>> {
+        vec![2..4, 4..4, 4..6, 9..9, 10..12].into_iter()
+    }
+
+    #[test]
+    fn build_default() {
+        let actual = every_range_builder()
+            .end(14)
+            .build(ranges())
+            .collect::<Vec<_>>();
+        let expected = ranges().every_range(14).collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn build_single_options() {
+        let builder = every_range_builder().end(14);
+
+        let actual = builder.start(1).build(ranges()).collect::<Vec<_>>();
+        let expected = ranges().every_range_from(1, 14).collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+
+        let actual = builder
+            .skip_leading_gap(true)
+            .build(ranges())
+            .collect::<Vec<_>>();
+        let expected = ranges().every_range_no_leading(14).collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+
+        let actual = builder
+            .skip_trailing_gap(true)
+            .build(ranges())
+            .collect::<Vec<_>>();
+        let expected = ranges().every_range_no_trailing(14).collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+
+        let actual = builder
+            .end(11)
+            .lenient(true)
+            .build(ranges())
+            .collect::<Vec<_>>();
+        let expected = ranges().every_range_clamped(11).collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+
+        let actual = builder
+            .skip_empty_gaps(true)
+            .build(ranges())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            [
+                (Excluded, 0..2),
+                (Included, 2..4),
+                (Included, 4..6),
+                (Excluded, 6..10),
+                (Included, 10..12),
+                (Excluded, 12..14),
+            ]
+        );
+    }
+
+    #[test]
+    fn build_combined_options() {
+        let actual = every_range_builder()
+            .start(1)
+            .end(11)
+            .skip_empty_gaps(true)
+            .skip_leading_gap(true)
+            .skip_trailing_gap(true)
+            .lenient(true)
+            .build(vec![0..2, 2..4, 4..4, 3..5, 6..8, 10..12].into_iter())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Included, 2..4),
+                (Excluded, 4..6),
+                (Included, 6..8),
+                (Excluded, 8..10),
+                (Included, 10..11),
+            ]
+        );
+    }
+
+    #[test]
+    fn build_combined_matches_chained() {
+        let actual = every_range_builder()
+            .start(1)
+            .end(14)
+            .skip_leading_gap(true)
+            .skip_trailing_gap(true)
+            .build(ranges())
+            .collect::<Vec<_>>();
+        let expected = ranges()
+            .every_range_from(1, 14)
+            .skip_leading()
+            .skip_trailing()
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    #[should_panic = "assertion failed: self.start <= self.end"]
+    fn build_start_after_end() {
+        every_range_builder().start(7).end(6).build(ranges());
+    }
+
+    #[test]
+    fn build_skip_trailing_bisect() {
+        let iter = every_range_builder()
+            .end(14)
+            .skip_trailing_gap(true)
+            .build(ranges());
+
+        let (left, right) = iter.bisect(11);
+        assert_eq!(left.last(), Some(&(Included, 10..11)));
+        assert_eq!(right.collect::<Vec<_>>(), [(Included, 11..12)]);
+    }
+}
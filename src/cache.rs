@@ -0,0 +1,170 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+use std::vec;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `CachedFirstN` holds the first `n` items of an [`EveryRangeIter`],
+/// allowing random access to them.
+///
+/// This `struct` is created by the [`cache_first_n`]
+/// method on [`EveryRangeIter`].
+///
+/// [`cache_first_n`]: struct.EveryRangeIter.html#method.cache_first_n
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+#[derive(PartialEq, Clone, Debug)]
+pub struct CachedFirstN {
+    items: Vec<(EveryRangeKind, Range<usize>)>,
+}
+
+impl CachedFirstN {
+    /// Returns the `i`th cached item, or `None` if `i` is out of bounds.
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<&(EveryRangeKind, Range<usize>)> {
+        self.items.get(i)
+    }
+
+    /// Returns the amount of cached items.
+    ///
+    /// This is less than `n` if the iterator yielded fewer than `n` items.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if no items were cached.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the cached items as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[(EveryRangeKind, Range<usize>)] {
+        &self.items
+    }
+}
+
+/// `CachingIter` yields the items cached in a [`CachedFirstN`],
+/// followed by the remaining items of the inner [`EveryRangeIter`].
+///
+/// This `struct` is created by the [`cache_first_n`]
+/// method on [`EveryRangeIter`].
+///
+/// [`cache_first_n`]: struct.EveryRangeIter.html#method.cache_first_n
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`CachedFirstN`]: struct.CachedFirstN.html
+pub struct CachingIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    cached: vec::IntoIter<(EveryRangeKind, Range<usize>)>,
+    iter: EveryRangeIter<I>,
+}
+
+impl<I> fmt::Debug for CachingIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachingIter")
+            .field("cached", &self.cached)
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Pre-fetch the first `n` items into a [`CachedFirstN`], and return
+    /// it alongside a [`CachingIter`], which yields the cached items first,
+    /// and then lazily continues with the remaining items.
+    ///
+    /// [`CachedFirstN`]: struct.CachedFirstN.html
+    /// [`CachingIter`]: struct.CachingIter.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let (preview, iter) = vec![2..4, 6..8].into_iter().every_range(10).cache_first_n(2);
+    ///
+    /// assert_eq!(preview.len(), 2);
+    /// assert_eq!(preview.get(1), Some(&(Included, 2..4)));
+    /// assert_eq!(preview.get(2), None);
+    ///
+    /// assert_eq!(iter.count(), 5);
+    /// ```
+    pub fn cache_first_n(mut self, n: usize) -> (CachedFirstN, CachingIter<I>) {
+        let items = self.by_ref().take(n).collect::<Vec<_>>();
+
+        let iter = CachingIter {
+            cached: items.clone().into_iter(),
+            iter: self,
+        };
+
+        (CachedFirstN { items }, iter)
+    }
+}
+
+impl<I> Iterator for CachingIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cached.next().or_else(|| self.iter.next())
+    }
+}
+
+impl<I> FusedIterator for CachingIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn cache_first_n() {
+        let text = "Foo12Bar34Baz56";
+
+        let expected = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .collect::<Vec<_>>();
+
+        let (cached, iter) = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .cache_first_n(3);
+
+        assert_eq!(cached.as_slice(), &expected[..3]);
+        assert_eq!(cached.get(0), Some(&(Excluded, 0..3)));
+        assert_eq!(iter.collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn cache_first_n_more_than_available() {
+        let (cached, mut iter) = [2..4, 6..8]
+            .iter()
+            .cloned()
+            .every_range(8)
+            .cache_first_n(10);
+
+        assert_eq!(cached.len(), 4);
+        assert_eq!(iter.by_ref().count(), 4);
+        assert_eq!(iter.next(), None);
+
+        let (cached, iter) = Vec::new().into_iter().every_range(0).cache_first_n(10);
+        assert!(cached.is_empty());
+        assert_eq!(iter.count(), 0);
+    }
+}
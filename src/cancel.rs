@@ -0,0 +1,156 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `CancellationToken` can be used to cancel a [`CancellableIter`],
+/// possibly from another thread.
+///
+/// Clones of a `CancellationToken` share the same state, i.e.
+/// cancelling one clone cancels all of them.
+///
+/// [`CancellableIter`]: struct.CancellableIter.html
+#[derive(Clone, Default, Debug)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Create a new `CancellationToken` which is not cancelled.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the `CancellationToken` and all of its clones.
+    #[inline]
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the `CancellationToken` has been cancelled.
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// `CancellableIter` stops iterating when its [`CancellationToken`]
+/// has been cancelled.
+///
+/// This `struct` is created by the [`with_cancellation_token`]
+/// method on [`EveryRangeIter`].
+///
+/// [`with_cancellation_token`]: struct.EveryRangeIter.html#method.with_cancellation_token
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`CancellationToken`]: struct.CancellationToken.html
+pub struct CancellableIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+    token: CancellationToken,
+}
+
+impl<I> fmt::Debug for CancellableIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancellableIter")
+            .field("iter", &self.iter)
+            .field("token", &self.token)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`CancellableIter`], which checks `token` before
+    /// each item, and returns `None` when `token` has been cancelled.
+    ///
+    /// [`CancellableIter`]: struct.CancellableIter.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{CancellationToken, EveryRange, EveryRangeKind::*};
+    ///
+    /// let token = CancellationToken::new();
+    ///
+    /// let mut iter = vec![2..4, 6..8]
+    ///     .into_iter()
+    ///     .every_range(10)
+    ///     .with_cancellation_token(token.clone());
+    ///
+    /// assert_eq!(iter.next(), Some((Excluded, 0..2)));
+    ///
+    /// token.cancel();
+    ///
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn with_cancellation_token(self, token: CancellationToken) -> CancellableIter<I> {
+        CancellableIter { iter: self, token }
+    }
+}
+
+impl<I> Iterator for CancellableIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.token.is_cancelled() {
+            None
+        } else {
+            self.iter.next()
+        }
+    }
+}
+
+impl<I> FusedIterator for CancellableIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EveryRange;
+    use std::thread;
+
+    #[test]
+    fn cancellation_token_uncancelled() {
+        let token = CancellationToken::new();
+
+        let count = [3..5, 8..10]
+            .iter()
+            .cloned()
+            .every_range(15)
+            .with_cancellation_token(token.clone())
+            .count();
+
+        assert_eq!(count, 5);
+        assert!(!token.is_cancelled());
+    }
+
+    #[test]
+    fn cancellation_token_from_thread() {
+        let token = CancellationToken::new();
+
+        let mut iter = (0..100)
+            .map(|i| (i * 2)..(i * 2 + 1))
+            .every_range(200)
+            .with_cancellation_token(token.clone());
+
+        assert!(iter.next().is_some());
+
+        thread::spawn(move || token.cancel()).join().unwrap();
+
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+}
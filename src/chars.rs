@@ -0,0 +1,152 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// Count the characters in `bytes`, i.e. the bytes that
+/// are not UTF-8 continuation bytes.
+#[inline]
+fn count_chars(bytes: &[u8]) -> usize {
+    bytes.iter().filter(|&&b| (b & 0xC0) != 0x80).count()
+}
+
+/// `CharOffsetIndexedIter` annotates the byte ranges of an
+/// [`EveryRangeIter`] with their equivalent char ranges.
+///
+/// This `struct` is created by the [`with_char_offset_index`]
+/// method on [`EveryRangeIter`].
+///
+/// [`with_char_offset_index`]: struct.EveryRangeIter.html#method.with_char_offset_index
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct CharOffsetIndexedIter<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+    text: &'a str,
+    byte_pos: usize,
+    char_pos: usize,
+}
+
+impl<'a, I> fmt::Debug for CharOffsetIndexedIter<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CharOffsetIndexedIter")
+            .field("iter", &self.iter)
+            .field("text", &self.text)
+            .field("byte_pos", &self.byte_pos)
+            .field("char_pos", &self.char_pos)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`CharOffsetIndexedIter`], which yields
+    /// `(EveryRangeKind, Range<usize>, Range<usize>)`, where the first
+    /// range is the byte range, and the second range is the equivalent
+    /// range of char indices into `text`.
+    ///
+    /// The char index is tracked with a running cursor, so each byte
+    /// of `text` is only visited once, without any allocations.
+    ///
+    /// [`CharOffsetIndexedIter`]: struct.CharOffsetIndexedIter.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let text = "Føø Bår";
+    ///
+    /// let items = vec![6..10]
+    ///     .into_iter()
+    ///     .every_range(text.len())
+    ///     .with_char_offset_index(text)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Excluded, 0..6, 0..4), (Included, 6..10, 4..7)]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any range is out of bounds of `text`.
+    #[inline]
+    pub fn with_char_offset_index(self, text: &str) -> CharOffsetIndexedIter<'_, I> {
+        CharOffsetIndexedIter {
+            iter: self,
+            text,
+            byte_pos: 0,
+            char_pos: 0,
+        }
+    }
+}
+
+impl<'a, I> Iterator for CharOffsetIndexedIter<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (kind, range) = self.iter.next()?;
+
+        let bytes = self.text.as_bytes();
+
+        let char_start = self.char_pos + count_chars(&bytes[self.byte_pos..range.start]);
+        let char_end = char_start + count_chars(&bytes[range.clone()]);
+
+        self.byte_pos = range.end;
+        self.char_pos = char_end;
+
+        Some((kind, range, char_start..char_end))
+    }
+}
+
+impl<'a, I> FusedIterator for CharOffsetIndexedIter<'a, I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn with_char_offset_index() {
+        let text = "Føø12Bår34Bäz56";
+
+        let items = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .with_char_offset_index(text)
+            .collect::<Vec<_>>();
+
+        let chars = text.chars().collect::<Vec<_>>();
+
+        assert_eq!(items.len(), 9);
+        for (_, bytes, char_range) in items {
+            assert_eq!(
+                text[bytes].chars().collect::<Vec<_>>(),
+                chars[char_range].to_vec()
+            );
+        }
+    }
+
+    #[test]
+    fn with_char_offset_index_after_next() {
+        let text = "Føø Bår";
+
+        let mut iter = [0..5, 6..10].iter().cloned().every_range(text.len());
+        iter.next();
+
+        let items = iter.with_char_offset_index(text).collect::<Vec<_>>();
+
+        assert_eq!(items, [(Excluded, 5..6, 3..4), (Included, 6..10, 4..7)]);
+    }
+}
@@ -0,0 +1,162 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `SplitEveryNBytesIter` splits ranges at every multiple of `n` bytes.
+///
+/// This `struct` is created by the [`split_every_n_bytes`]
+/// method on [`EveryRangeIter`].
+///
+/// [`split_every_n_bytes`]: struct.EveryRangeIter.html#method.split_every_n_bytes
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct SplitEveryNBytesIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+    n: usize,
+    pending: Option<(EveryRangeKind, Range<usize>)>,
+}
+
+impl<I> fmt::Debug for SplitEveryNBytesIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitEveryNBytesIter")
+            .field("iter", &self.iter)
+            .field("n", &self.n)
+            .field("pending", &self.pending)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`SplitEveryNBytesIter`], which splits any range crossing
+    /// a multiple of `n`, into multiple ranges of the same kind.
+    ///
+    /// The yielded ranges cover the same `0..end` as before, but every
+    /// range is at most `n` bytes long, and never crosses a multiple of `n`.
+    ///
+    /// [`SplitEveryNBytesIter`]: struct.SplitEveryNBytesIter.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![2..7]
+    ///     .into_iter()
+    ///     .every_range(8)
+    ///     .split_every_n_bytes(4)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     items,
+    ///     [
+    ///         (Excluded, 0..2),
+    ///         (Included, 2..4),
+    ///         (Included, 4..7),
+    ///         (Excluded, 7..8),
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    pub fn split_every_n_bytes(self, n: usize) -> SplitEveryNBytesIter<I> {
+        assert!(n != 0, "n must not be 0");
+
+        SplitEveryNBytesIter {
+            iter: self,
+            n,
+            pending: None,
+        }
+    }
+}
+
+impl<I> Iterator for SplitEveryNBytesIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (kind, range) = match self.pending.take() {
+            Some(item) => item,
+            None => self.iter.next()?,
+        };
+
+        let boundary = (range.start / self.n + 1).saturating_mul(self.n);
+        if range.end > boundary {
+            self.pending = Some((kind, boundary..range.end));
+            Some((kind, range.start..boundary))
+        } else {
+            Some((kind, range))
+        }
+    }
+}
+
+impl<I> FusedIterator for SplitEveryNBytesIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn split_every_n_bytes() {
+        let text = "Foo12Bar34Baz56";
+
+        let actual = [0..5, 10..13]
+            .iter()
+            .cloned()
+            .every_range(text.len())
+            .split_every_n_bytes(4)
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Included, "Foo1"),
+                (Included, "2"),
+                (Excluded, "Bar"),
+                (Excluded, "34"),
+                (Included, "Ba"),
+                (Included, "z"),
+                (Excluded, "56"),
+            ]
+        );
+    }
+
+    #[test]
+    fn split_every_n_bytes_lengths() {
+        for n in 1..12 {
+            let items = [0..5, 5..17, 20..21]
+                .iter()
+                .cloned()
+                .every_range(30)
+                .split_every_n_bytes(n)
+                .collect::<Vec<_>>();
+
+            assert_eq!(items.first().unwrap().1.start, 0);
+            assert_eq!(items.last().unwrap().1.end, 30);
+            for w in items.windows(2) {
+                assert_eq!(w[0].1.end, w[1].1.start);
+            }
+            for (_, range) in items {
+                assert!(range.len() <= n);
+                assert_eq!(range.start / n, (range.end - 1) / n);
+            }
+        }
+    }
+}
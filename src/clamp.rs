@@ -0,0 +1,176 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::fallible::check_range;
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// Clamps or skips the ranges that would otherwise cause
+/// `EveryRangeIter` to panic.
+struct ClampedRanges<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: I,
+    prev_end: usize,
+    end: usize,
+}
+
+impl<I> Iterator for ClampedRanges<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for range in &mut self.iter {
+            let range = range.start..range.end.min(self.end);
+
+            if check_range(self.prev_end, self.end, &range).is_ok() {
+                self.prev_end = range.end;
+                return Some(range);
+            }
+        }
+
+        None
+    }
+}
+
+/// `ClampedEveryRangeIter` is a lenient [`EveryRangeIter`], which clamps
+/// or skips invalid ranges instead of panicking.
+///
+/// This `struct` is created by the [`every_range_clamped`]
+/// method on [`EveryRange`].
+///
+/// [`every_range_clamped`]: trait.EveryRange.html#method.every_range_clamped
+/// [`EveryRange`]: trait.EveryRange.html
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct ClampedEveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<ClampedRanges<I>>,
+}
+
+impl<I> fmt::Debug for ClampedEveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClampedEveryRangeIter")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I> ClampedEveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    #[inline]
+    pub(crate) fn new(iter: I, end: usize) -> Self {
+        let ranges = ClampedRanges {
+            iter,
+            prev_end: 0,
+            end,
+        };
+
+        Self {
+            iter: EveryRangeIter::new(ranges, end),
+        }
+    }
+}
+
+impl<I> Iterator for ClampedEveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+impl<I> FusedIterator for ClampedEveryRangeIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn range_start_after_end() {
+        let actual = [0..2, 4..6]
+            .iter()
+            .cloned()
+            .every_range_clamped(3)
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, [(Included, 0..2), (Excluded, 2..3)]);
+    }
+
+    #[test]
+    fn range_end_after_end() {
+        let actual = [0..2, 4..6]
+            .iter()
+            .cloned()
+            .every_range_clamped(5)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [(Included, 0..2), (Excluded, 2..4), (Included, 4..5)]
+        );
+    }
+
+    #[test]
+    fn range_start_after_index() {
+        let actual = [0..4, 2..6]
+            .iter()
+            .cloned()
+            .every_range_clamped(5)
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, [(Included, 0..4), (Excluded, 4..5)]);
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn ranges_out_of_order() {
+        let cases = [
+            ([4..6, 0..2, 8..10], vec![4..6, 8..10]),
+            ([8..10, 0..2, 4..6], vec![8..10]),
+            ([0..2, 8..10, 4..6], vec![0..2, 8..10]),
+            ([4..6, 8..10, 0..2], vec![4..6, 8..10]),
+            ([8..10, 4..6, 0..2], vec![8..10]),
+        ];
+
+        for (ranges, accepted) in cases.iter() {
+            let actual = ranges
+                .iter()
+                .cloned()
+                .every_range_clamped(20)
+                .collect::<Vec<_>>();
+            let expected = accepted.iter().cloned().every_range(20).collect::<Vec<_>>();
+
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn every_range_clamped_valid() {
+        let text = "Foo12Bar34Baz56";
+
+        let ranges = || {
+            text.match_indices(char::is_numeric)
+                .map(|(start, part)| start..(start + part.len()))
+        };
+
+        let actual = ranges().every_range_clamped(text.len()).collect::<Vec<_>>();
+        let expected = ranges().every_range(text.len()).collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,59 @@
+use std::ops::Range;
+
+use crate::EveryRangeKind;
+
+/// `Coalesce` merges consecutive items of the same kind, by buffering
+/// one pending item, and extending it by every following item of the
+/// same kind.
+#[derive(Debug)]
+pub(crate) struct Coalesce {
+    /// If `Some`, then only items of this kind are merged.
+    only: Option<EveryRangeKind>,
+    pending: Option<(EveryRangeKind, Range<usize>)>,
+}
+
+impl Coalesce {
+    /// Create a `Coalesce` which merges consecutive items of any kind.
+    #[inline]
+    pub(crate) fn all() -> Self {
+        Self {
+            only: None,
+            pending: None,
+        }
+    }
+
+    /// Create a `Coalesce` which only merges consecutive items of `kind`.
+    #[inline]
+    pub(crate) fn only(kind: EveryRangeKind) -> Self {
+        Self {
+            only: Some(kind),
+            pending: None,
+        }
+    }
+
+    /// Push an item, returning the previously pending item,
+    /// if `item` could not be merged into it.
+    #[inline]
+    pub(crate) fn push(
+        &mut self,
+        (kind, range): (EveryRangeKind, Range<usize>),
+    ) -> Option<(EveryRangeKind, Range<usize>)> {
+        match &mut self.pending {
+            Some((pending_kind, pending))
+                if *pending_kind == kind
+                    && pending.end == range.start
+                    && (self.only.is_none() || self.only == Some(kind)) =>
+            {
+                pending.end = range.end;
+                None
+            }
+            _ => self.pending.replace((kind, range)),
+        }
+    }
+
+    /// Take the pending item, if any.
+    #[inline]
+    pub(crate) fn finish(&mut self) -> Option<(EveryRangeKind, Range<usize>)> {
+        self.pending.take()
+    }
+}
@@ -0,0 +1,245 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Consume the `EveryRangeIter` and concatenate all
+    /// [`Excluded`] substrings of `text`, i.e. discarding
+    /// all [`Included`] substrings.
+    ///
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::EveryRange;
+    ///
+    /// let text = "Foo rust-lang.org Bar";
+    ///
+    /// let output = vec![4..17]
+    ///     .into_iter()
+    ///     .every_range(text.len())
+    ///     .concat_excluded_only(text);
+    ///
+    /// assert_eq!(output, "Foo  Bar");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any range is out of bounds of `text`,
+    /// or not on a UTF-8 character boundary.
+    #[inline]
+    pub fn concat_excluded_only(self, text: &str) -> String {
+        self.concat_kind(text, EveryRangeKind::Excluded)
+    }
+
+    /// Consume the `EveryRangeIter` and concatenate all
+    /// [`Included`] substrings of `text`, i.e. discarding
+    /// all [`Excluded`] substrings.
+    ///
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::EveryRange;
+    ///
+    /// let text = "Foo rust-lang.org Bar";
+    ///
+    /// let output = vec![4..17]
+    ///     .into_iter()
+    ///     .every_range(text.len())
+    ///     .concat_included_only(text);
+    ///
+    /// assert_eq!(output, "rust-lang.org");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any range is out of bounds of `text`,
+    /// or not on a UTF-8 character boundary.
+    #[inline]
+    pub fn concat_included_only(self, text: &str) -> String {
+        self.concat_kind(text, EveryRangeKind::Included)
+    }
+
+    /// Consume the `EveryRangeIter` and concatenate the substrings
+    /// of `s`, where each [`Included`] substring is mapped with
+    /// `on_included`, and each [`Excluded`] substring is mapped
+    /// with `on_excluded`.
+    ///
+    /// *See [`collect_str_replace_included`] for only mapping
+    /// the [`Included`] substrings.*
+    ///
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    /// [`collect_str_replace_included`]: struct.EveryRangeIter.html#method.collect_str_replace_included
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use every_range::EveryRange;
+    ///
+    /// let text = "Foo rust-lang.org Bar";
+    ///
+    /// let output = vec![4..17].into_iter().every_range(text.len()).collect_str_with(
+    ///     text,
+    ///     |url| format!("<{}>", url).into(),
+    ///     |s| Cow::Owned(s.to_uppercase()),
+    /// );
+    ///
+    /// assert_eq!(output, "FOO <rust-lang.org> BAR");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any range is out of bounds of `s`,
+    /// or not on a UTF-8 character boundary.
+    pub fn collect_str_with<'a, F, G>(self, s: &'a str, on_included: F, on_excluded: G) -> String
+    where
+        F: Fn(&'a str) -> Cow<'a, str>,
+        G: Fn(&'a str) -> Cow<'a, str>,
+    {
+        let mut output = String::new();
+
+        for (kind, range) in self {
+            let part = match kind {
+                EveryRangeKind::Included => on_included(&s[range]),
+                EveryRangeKind::Excluded => on_excluded(&s[range]),
+            };
+
+            output.push_str(&part);
+        }
+
+        output
+    }
+
+    /// Consume the `EveryRangeIter` and concatenate the substrings
+    /// of `s`, where each [`Included`] substring is mapped with `f`,
+    /// while [`Excluded`] substrings are left unchanged.
+    ///
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::EveryRange;
+    ///
+    /// let text = "Foo rust-lang.org Bar";
+    ///
+    /// let output = vec![4..17]
+    ///     .into_iter()
+    ///     .every_range(text.len())
+    ///     .collect_str_replace_included(text, |url| format!("<{}>", url).into());
+    ///
+    /// assert_eq!(output, "Foo <rust-lang.org> Bar");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any range is out of bounds of `s`,
+    /// or not on a UTF-8 character boundary.
+    #[inline]
+    pub fn collect_str_replace_included<'a, F>(self, s: &'a str, f: F) -> String
+    where
+        F: Fn(&'a str) -> Cow<'a, str>,
+    {
+        self.collect_str_with(s, f, Cow::Borrowed)
+    }
+
+    fn concat_kind(self, text: &str, kind: EveryRangeKind) -> String {
+        let mut output = String::new();
+
+        for (_, range) in self.filter(|(k, _)| *k == kind) {
+            output.push_str(&text[range]);
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use crate::EveryRange;
+
+    #[test]
+    fn concat_excluded_only() {
+        let text = "Foo12Bar34Baz56";
+
+        let output = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .concat_excluded_only(text);
+
+        assert_eq!(output, "FooBarBaz");
+    }
+
+    #[test]
+    fn concat_included_only() {
+        let text = "Foo12Bar34Baz56";
+
+        let output = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .concat_included_only(text);
+
+        assert_eq!(output, "123456");
+    }
+
+    #[test]
+    fn collect_str_with() {
+        let text = "Foo12Bar34Baz56";
+
+        let ranges = || {
+            text.match_indices(char::is_numeric)
+                .map(|(start, part)| start..(start + part.len()))
+        };
+
+        let output =
+            ranges()
+                .every_range(text.len())
+                .collect_str_with(text, Cow::Borrowed, Cow::Borrowed);
+        assert_eq!(output.len(), text.len());
+        assert_eq!(output, text);
+
+        let output = ranges().every_range(text.len()).collect_str_with(
+            text,
+            |s| format!("[{}]", s).into(),
+            |s| s.to_lowercase().into(),
+        );
+        assert_eq!(output, "foo[1][2]bar[3][4]baz[5][6]");
+    }
+
+    #[test]
+    fn collect_str_replace_included() {
+        let text = "Foo12Bar34Baz56";
+
+        let ranges = || {
+            text.match_indices(char::is_numeric)
+                .map(|(start, part)| start..(start + part.len()))
+        };
+
+        let output = ranges()
+            .every_range(text.len())
+            .collect_str_replace_included(text, Cow::Borrowed);
+        assert_eq!(output.len(), text.len());
+        assert_eq!(output, text);
+
+        let output = ranges()
+            .every_range(text.len())
+            .collect_str_replace_included(text, |_| "#".into());
+        assert_eq!(output, "Foo##Bar##Baz##");
+    }
+}
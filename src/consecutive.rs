@@ -0,0 +1,134 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::coalesce::Coalesce;
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `MergeConsecutiveIter` merges consecutive items of the same kind.
+///
+/// This `struct` is created by the [`merge_consecutive_kinds`]
+/// method on [`EveryRangeIter`].
+///
+/// [`merge_consecutive_kinds`]: struct.EveryRangeIter.html#method.merge_consecutive_kinds
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct MergeConsecutiveIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+    coalesce: Coalesce,
+}
+
+impl<I> fmt::Debug for MergeConsecutiveIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MergeConsecutiveIter")
+            .field("iter", &self.iter)
+            .field("coalesce", &self.coalesce)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`MergeConsecutiveIter`], which merges consecutive
+    /// items of the same kind into a single item, e.g. adjacent
+    /// single char [`Included`] ranges into a single word.
+    ///
+    /// [`MergeConsecutiveIter`]: struct.MergeConsecutiveIter.html
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![0..1, 1..2, 4..5]
+    ///     .into_iter()
+    ///     .every_range(6)
+    ///     .merge_consecutive_kinds()
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     items,
+    ///     [(Included, 0..2), (Excluded, 2..4), (Included, 4..5), (Excluded, 5..6)]
+    /// );
+    /// ```
+    #[inline]
+    pub fn merge_consecutive_kinds(self) -> MergeConsecutiveIter<I> {
+        MergeConsecutiveIter {
+            iter: self,
+            coalesce: Coalesce::all(),
+        }
+    }
+}
+
+impl<I> Iterator for MergeConsecutiveIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for item in &mut self.iter {
+            if let Some(item) = self.coalesce.push(item) {
+                return Some(item);
+            }
+        }
+
+        self.coalesce.finish()
+    }
+}
+
+impl<I> FusedIterator for MergeConsecutiveIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn merge_consecutive_kinds() {
+        let text = "Foo12Bar34Baz56";
+
+        let actual = text
+            .match_indices(char::is_alphabetic)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .merge_consecutive_kinds()
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Included, "Foo"),
+                (Excluded, "12"),
+                (Included, "Bar"),
+                (Excluded, "34"),
+                (Included, "Baz"),
+                (Excluded, "56"),
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_consecutive_kinds_empty() {
+        let actual = [0..0, 0..2, 2..2, 4..4]
+            .iter()
+            .cloned()
+            .every_range(4)
+            .merge_consecutive_kinds()
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [(Included, 0..2), (Excluded, 2..4), (Included, 4..4)]
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{DebugPlaceholder, EveryRangeKind};
+
+/// `AssertNoOverlapIter` asserts that consecutive items are contiguous.
+///
+/// This `struct` is created by the [`assert_no_overlapping_output`]
+/// method on [`EveryRangeExt`].
+///
+/// [`assert_no_overlapping_output`]: trait.EveryRangeExt.html#method.assert_no_overlapping_output
+/// [`EveryRangeExt`]: trait.EveryRangeExt.html
+pub struct AssertNoOverlapIter<I>
+where
+    I: Iterator<Item = (EveryRangeKind, Range<usize>)>,
+{
+    iter: I,
+    #[cfg(debug_assertions)]
+    prev_end: Option<usize>,
+}
+
+impl<I> fmt::Debug for AssertNoOverlapIter<I>
+where
+    I: Iterator<Item = (EveryRangeKind, Range<usize>)>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = f.debug_struct("AssertNoOverlapIter");
+        s.field("iter", &DebugPlaceholder("<iterator>"));
+        #[cfg(debug_assertions)]
+        s.field("prev_end", &self.prev_end);
+        s.finish()
+    }
+}
+
+impl<I> AssertNoOverlapIter<I>
+where
+    I: Iterator<Item = (EveryRangeKind, Range<usize>)>,
+{
+    #[inline]
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            #[cfg(debug_assertions)]
+            prev_end: None,
+        }
+    }
+}
+
+impl<I> Iterator for AssertNoOverlapIter<I>
+where
+    I: Iterator<Item = (EveryRangeKind, Range<usize>)>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (kind, range) = self.iter.next()?;
+
+        #[cfg(debug_assertions)]
+        {
+            if let Some(prev_end) = self.prev_end {
+                assert!(
+                    prev_end == range.start,
+                    "{:?} range {:?} does not start at the end of the previous range ({})",
+                    kind,
+                    range,
+                    prev_end,
+                );
+            }
+
+            self.prev_end = Some(range.end);
+        }
+
+        Some((kind, range))
+    }
+}
+
+impl<I> FusedIterator for AssertNoOverlapIter<I> where
+    I: FusedIterator<Item = (EveryRangeKind, Range<usize>)>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{EveryRange, EveryRangeExt};
+
+    #[test]
+    fn assert_no_overlapping_output() {
+        let text = "Foo12Bar34Baz56";
+
+        let count = text
+            .match_indices(char::is_alphabetic)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .assert_no_overlapping_output()
+            .count();
+
+        assert_eq!(count, 12);
+    }
+
+    #[test]
+    fn assert_no_overlapping_output_empty_ranges() {
+        let count = [0..0, 0..0, 2..2, 4..5]
+            .iter()
+            .cloned()
+            .every_range(5)
+            .assert_no_overlapping_output()
+            .count();
+
+        assert_eq!(count, 6);
+    }
+
+    #[test]
+    fn assert_no_overlapping_output_mapped() {
+        let count = [2..4, 6..8]
+            .iter()
+            .cloned()
+            .every_range(10)
+            .map(|(kind, range)| (kind, (range.start + 10)..(range.end + 10)))
+            .assert_no_overlapping_output()
+            .count();
+
+        assert_eq!(count, 5);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "Included range 2..5 does not start at the end of the previous range (3)"]
+    fn assert_no_overlapping_output_overlap() {
+        [2..4, 6..8]
+            .iter()
+            .cloned()
+            .every_range(10)
+            .map(|(kind, range)| (kind, range.start..(range.end + 1).min(10)))
+            .assert_no_overlapping_output()
+            .for_each(|_| {});
+    }
+}
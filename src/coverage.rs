@@ -0,0 +1,213 @@
+use std::ops::Range;
+use std::vec;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `CoverageVec` is a dense representation of an [`EveryRangeIter`],
+/// holding the [`EveryRangeKind`] of every position in `0..end`.
+///
+/// This `struct` is created by the [`into_coverage_vec`]
+/// method on [`EveryRangeIter`].
+///
+/// [`into_coverage_vec`]: struct.EveryRangeIter.html#method.into_coverage_vec
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`EveryRangeKind`]: enum.EveryRangeKind.html
+#[derive(PartialEq, Clone, Debug)]
+pub struct CoverageVec(Vec<EveryRangeKind>);
+
+impl CoverageVec {
+    /// Returns the kind of the range covering `pos`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos` is out of bounds.
+    #[inline]
+    pub fn kind_at(&self, pos: usize) -> EveryRangeKind {
+        self.0[pos]
+    }
+
+    /// Returns the amount of positions, i.e. the `end`
+    /// of the [`EveryRangeIter`].
+    ///
+    /// [`EveryRangeIter`]: struct.EveryRangeIter.html
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the `CoverageVec` has no positions.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the kinds of all positions.
+    #[inline]
+    pub fn as_slice(&self) -> &[EveryRangeKind] {
+        &self.0
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Consume the `EveryRangeIter` and create a [`CoverageVec`] of length
+    /// `end`, where each position holds the kind of the range covering it.
+    ///
+    /// Positions not covered by any remaining item, e.g. before the start
+    /// of [`every_range_from`] or of a partially consumed `EveryRangeIter`,
+    /// are [`Excluded`].
+    ///
+    /// *Note that zero-length ranges do not cover any position,
+    /// and are thereby lost.*
+    ///
+    /// [`every_range_from`]: trait.EveryRange.html#method.every_range_from
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    /// [`CoverageVec`]: struct.CoverageVec.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let coverage = vec![2..4].into_iter().every_range(6).into_coverage_vec();
+    ///
+    /// assert_eq!(coverage.len(), 6);
+    /// assert_eq!(coverage.kind_at(1), Excluded);
+    /// assert_eq!(coverage.kind_at(2), Included);
+    /// assert_eq!(coverage.kind_at(4), Excluded);
+    /// ```
+    pub fn into_coverage_vec(self) -> CoverageVec {
+        let end = self.end;
+
+        let mut kinds = Vec::with_capacity(end);
+        kinds.resize(self.index, EveryRangeKind::Excluded);
+
+        for (kind, range) in self {
+            kinds.extend(range.map(|_| kind));
+        }
+
+        kinds.resize(end, EveryRangeKind::Excluded);
+
+        CoverageVec(kinds)
+    }
+}
+
+impl EveryRangeIter<vec::IntoIter<Range<usize>>> {
+    /// Create an `EveryRangeIter` from a [`CoverageVec`], where each run
+    /// of consecutive [`Included`] positions become an [`Included`] range.
+    ///
+    /// [`CoverageVec`]: struct.CoverageVec.html
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeIter};
+    ///
+    /// let coverage = vec![2..4].into_iter().every_range(6).into_coverage_vec();
+    /// let iter = EveryRangeIter::from_coverage_vec(coverage);
+    ///
+    /// assert!(iter.eq(vec![2..4].into_iter().every_range(6)));
+    /// ```
+    pub fn from_coverage_vec(v: CoverageVec) -> Self {
+        let end = v.len();
+
+        let mut ranges = Vec::new();
+        let mut start = None;
+
+        for (i, &kind) in v.0.iter().enumerate() {
+            match (kind, start) {
+                (EveryRangeKind::Included, None) => start = Some(i),
+                (EveryRangeKind::Excluded, Some(s)) => {
+                    ranges.push(s..i);
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(s) = start {
+            ranges.push(s..end);
+        }
+
+        EveryRangeIter::new(ranges.into_iter(), end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn into_coverage_vec() {
+        let text = "Foo12Bar34Baz56";
+
+        let coverage = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .into_coverage_vec();
+
+        assert_eq!(coverage.len(), text.len());
+
+        for (i, c) in text.char_indices() {
+            let expected = if c.is_numeric() { Included } else { Excluded };
+            assert_eq!(coverage.kind_at(i), expected);
+        }
+    }
+
+    #[test]
+    fn from_coverage_vec() {
+        let ranges = [0..2, 2..3, 5..8, 10..12];
+
+        let coverage = ranges.iter().cloned().every_range(12).into_coverage_vec();
+        let actual = EveryRangeIter::from_coverage_vec(coverage).collect::<Vec<_>>();
+
+        // Adjacent ranges are indistinguishable in a `CoverageVec`
+        assert_eq!(
+            actual,
+            [
+                (Included, 0..3),
+                (Excluded, 3..5),
+                (Included, 5..8),
+                (Excluded, 8..10),
+                (Included, 10..12),
+            ]
+        );
+    }
+
+    #[test]
+    fn from_coverage_vec_empty() {
+        let coverage = Vec::new().into_iter().every_range(0).into_coverage_vec();
+        assert!(coverage.is_empty());
+
+        assert_eq!(EveryRangeIter::from_coverage_vec(coverage).next(), None);
+    }
+
+    #[test]
+    fn into_coverage_vec_from() {
+        let coverage = std::iter::once(4..6)
+            .every_range_from(2, 8)
+            .into_coverage_vec();
+
+        assert_eq!(coverage.len(), 8);
+        assert_eq!(
+            coverage.as_slice(),
+            [Excluded, Excluded, Excluded, Excluded, Included, Included, Excluded, Excluded,]
+        );
+
+        let mut iter = [2..4, 6..8].iter().cloned().every_range(10);
+        assert_eq!(iter.next(), Some((Excluded, 0..2)));
+        assert_eq!(iter.next_back(), Some((Excluded, 8..10)));
+
+        let coverage = iter.into_coverage_vec();
+        assert_eq!(coverage.len(), 10);
+        assert_eq!(coverage.kind_at(2), Included);
+        assert_eq!(coverage.kind_at(6), Included);
+        assert_eq!(coverage.kind_at(9), Excluded);
+    }
+}
@@ -0,0 +1,270 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `AgreementKind` describes whether two [`EveryRangeIter`]s agree
+/// on the kind of a range.
+///
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum AgreementKind {
+    /// Both [`EveryRangeIter`]s yield [`Included`] for the range.
+    ///
+    /// [`EveryRangeIter`]: struct.EveryRangeIter.html
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    Included,
+
+    /// Both [`EveryRangeIter`]s yield [`Excluded`] for the range.
+    ///
+    /// [`EveryRangeIter`]: struct.EveryRangeIter.html
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    Excluded,
+
+    /// The [`EveryRangeIter`]s yield different kinds for the range.
+    ///
+    /// [`EveryRangeIter`]: struct.EveryRangeIter.html
+    Disagreed,
+}
+
+impl AgreementKind {
+    #[inline]
+    fn of(a: EveryRangeKind, b: EveryRangeKind) -> Self {
+        match (a, b) {
+            (EveryRangeKind::Included, EveryRangeKind::Included) => AgreementKind::Included,
+            (EveryRangeKind::Excluded, EveryRangeKind::Excluded) => AgreementKind::Excluded,
+            _ => AgreementKind::Disagreed,
+        }
+    }
+}
+
+/// `CrossProductIter` sweeps over two [`EveryRangeIter`]s simultaneously,
+/// yielding the [`AgreementKind`] of every overlap of their ranges.
+///
+/// This `struct` is created by the [`cross_product`]
+/// method on [`EveryRangeIter`].
+///
+/// [`cross_product`]: struct.EveryRangeIter.html#method.cross_product
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`AgreementKind`]: enum.AgreementKind.html
+pub struct CrossProductIter<I, J>
+where
+    I: Iterator<Item = Range<usize>>,
+    J: Iterator<Item = Range<usize>>,
+{
+    a: EveryRangeIter<I>,
+    b: EveryRangeIter<J>,
+    cur_a: Option<(EveryRangeKind, Range<usize>)>,
+    cur_b: Option<(EveryRangeKind, Range<usize>)>,
+}
+
+impl<I, J> fmt::Debug for CrossProductIter<I, J>
+where
+    I: Iterator<Item = Range<usize>>,
+    J: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CrossProductIter")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .field("cur_a", &self.cur_a)
+            .field("cur_b", &self.cur_b)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`CrossProductIter`], which sweeps over `self` and `other`
+    /// simultaneously, splitting their ranges at the boundaries of both.
+    ///
+    /// Each yielded range is an overlap of a range from `self` and a range
+    /// from `other`, along with an [`AgreementKind`] describing whether
+    /// they are both [`Included`], both [`Excluded`], or [`Disagreed`].
+    ///
+    /// This is useful for e.g. computing the agreement between two
+    /// independent annotators of the same text.
+    ///
+    /// Both `self` and `other` are expected to cover the same `0..end`.
+    /// Otherwise, iteration stops when either is exhausted. Zero-length
+    /// ranges do not overlap anything, and are thereby skipped.
+    ///
+    /// [`CrossProductIter`]: struct.CrossProductIter.html
+    /// [`AgreementKind`]: enum.AgreementKind.html
+    /// [`Included`]: enum.AgreementKind.html#variant.Included
+    /// [`Excluded`]: enum.AgreementKind.html#variant.Excluded
+    /// [`Disagreed`]: enum.AgreementKind.html#variant.Disagreed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{AgreementKind::*, EveryRange};
+    ///
+    /// let a = vec![2..6].into_iter().every_range(10);
+    /// let b = vec![4..8].into_iter().every_range(10);
+    ///
+    /// let items = a.cross_product(b).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     items,
+    ///     [
+    ///         (Excluded, 0..2),
+    ///         (Disagreed, 2..4),
+    ///         (Included, 4..6),
+    ///         (Disagreed, 6..8),
+    ///         (Excluded, 8..10),
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    pub fn cross_product<J>(self, other: EveryRangeIter<J>) -> CrossProductIter<I, J>
+    where
+        J: Iterator<Item = Range<usize>>,
+    {
+        CrossProductIter {
+            a: self,
+            b: other,
+            cur_a: None,
+            cur_b: None,
+        }
+    }
+}
+
+/// Returns the current non-empty item of `iter`,
+/// advancing `iter` if `cur` is `None`.
+#[inline]
+fn current<'a, I>(
+    cur: &'a mut Option<(EveryRangeKind, Range<usize>)>,
+    iter: &mut EveryRangeIter<I>,
+) -> Option<&'a (EveryRangeKind, Range<usize>)>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    if cur.is_none() {
+        *cur = iter.find(|(_, range)| range.start < range.end);
+    }
+
+    cur.as_ref()
+}
+
+impl<I, J> Iterator for CrossProductIter<I, J>
+where
+    I: Iterator<Item = Range<usize>>,
+    J: Iterator<Item = Range<usize>>,
+{
+    type Item = (AgreementKind, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (kind_a, a) = current(&mut self.cur_a, &mut self.a)?.clone();
+            let (kind_b, b) = current(&mut self.cur_b, &mut self.b)?.clone();
+
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+
+            if a.end == end {
+                self.cur_a = None;
+            }
+            if b.end == end {
+                self.cur_b = None;
+            }
+
+            if start < end {
+                return Some((AgreementKind::of(kind_a, kind_b), start..end));
+            }
+        }
+    }
+}
+
+impl<I, J> FusedIterator for CrossProductIter<I, J>
+where
+    I: Iterator<Item = Range<usize>>,
+    J: Iterator<Item = Range<usize>>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use std::iter;
+
+    use super::AgreementKind::*;
+    use crate::EveryRange;
+
+    #[test]
+    fn cross_product() {
+        let text = "Foo12Bar34Baz56";
+
+        let a = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len());
+        let b = text
+            .match_indices(char::is_uppercase)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len());
+
+        let actual = a
+            .cross_product(b)
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Disagreed, "F"),
+                (Excluded, "oo"),
+                (Disagreed, "1"),
+                (Disagreed, "2"),
+                (Disagreed, "B"),
+                (Excluded, "ar"),
+                (Disagreed, "3"),
+                (Disagreed, "4"),
+                (Disagreed, "B"),
+                (Excluded, "az"),
+                (Disagreed, "5"),
+                (Disagreed, "6"),
+            ]
+        );
+    }
+
+    #[test]
+    fn cross_product_identical() {
+        let ranges = [0..2, 2..2, 4..6, 6..9];
+
+        let a = ranges.iter().cloned().every_range(10);
+        let b = ranges.iter().cloned().every_range(10);
+
+        let actual = a.cross_product(b).collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            [
+                (Included, 0..2),
+                (Excluded, 2..4),
+                (Included, 4..6),
+                (Included, 6..9),
+                (Excluded, 9..10),
+            ]
+        );
+    }
+
+    #[test]
+    fn cross_product_different_end() {
+        let a = iter::once(2..4).every_range(5);
+        let b = iter::once(1..3).every_range(10);
+
+        let actual = a.cross_product(b).collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            [
+                (Excluded, 0..1),
+                (Disagreed, 1..2),
+                (Included, 2..3),
+                (Disagreed, 3..4),
+                (Excluded, 4..5),
+            ]
+        );
+    }
+}
@@ -0,0 +1,345 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::coalesce::Coalesce;
+use crate::{DebugPlaceholder, EveryRangeIter, EveryRangeKind};
+
+/// `DedupByRangeIter` drops consecutive items with equal ranges.
+///
+/// This `struct` is created by the [`dedup_by_range`] and
+/// [`dedup_by_range_and_kind`] methods on [`EveryRangeExt`].
+///
+/// [`dedup_by_range`]: trait.EveryRangeExt.html#method.dedup_by_range
+/// [`dedup_by_range_and_kind`]: trait.EveryRangeExt.html#method.dedup_by_range_and_kind
+/// [`EveryRangeExt`]: trait.EveryRangeExt.html
+pub struct DedupByRangeIter<I>
+where
+    I: Iterator<Item = (EveryRangeKind, Range<usize>)>,
+{
+    iter: I,
+    by_kind: bool,
+    prev: Option<(EveryRangeKind, Range<usize>)>,
+}
+
+impl<I> fmt::Debug for DedupByRangeIter<I>
+where
+    I: Iterator<Item = (EveryRangeKind, Range<usize>)>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DedupByRangeIter")
+            .field("iter", &DebugPlaceholder("<iterator>"))
+            .field("by_kind", &self.by_kind)
+            .field("prev", &self.prev)
+            .finish()
+    }
+}
+
+impl<I> DedupByRangeIter<I>
+where
+    I: Iterator<Item = (EveryRangeKind, Range<usize>)>,
+{
+    #[inline]
+    pub(crate) fn new(iter: I, by_kind: bool) -> Self {
+        Self {
+            iter,
+            by_kind,
+            prev: None,
+        }
+    }
+}
+
+impl<I> Iterator for DedupByRangeIter<I>
+where
+    I: Iterator<Item = (EveryRangeKind, Range<usize>)>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (kind, range) in &mut self.iter {
+            if let Some((prev_kind, prev_range)) = &self.prev {
+                if (*prev_range == range) && (!self.by_kind || (*prev_kind == kind)) {
+                    continue;
+                }
+            }
+
+            self.prev = Some((kind, range.clone()));
+            return Some((kind, range));
+        }
+
+        None
+    }
+}
+
+impl<I> FusedIterator for DedupByRangeIter<I> where
+    I: FusedIterator<Item = (EveryRangeKind, Range<usize>)>
+{
+}
+
+/// `DeduplicateByIter` converts [`Included`] ranges into [`Excluded`]
+/// ranges, when their key equals the key of the previous [`Included`] range.
+///
+/// This `struct` is created by the [`deduplicate_included_by`]
+/// method on [`EveryRangeIter`].
+///
+/// [`deduplicate_included_by`]: struct.EveryRangeIter.html#method.deduplicate_included_by
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`Included`]: enum.EveryRangeKind.html#variant.Included
+/// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+pub struct DeduplicateByIter<I, F, K>
+where
+    I: Iterator<Item = Range<usize>>,
+    F: FnMut(&Range<usize>) -> K,
+    K: PartialEq,
+{
+    iter: EveryRangeIter<I>,
+    key_fn: F,
+    key: Option<K>,
+    coalesce: Coalesce,
+}
+
+impl<I, F, K> fmt::Debug for DeduplicateByIter<I, F, K>
+where
+    I: Iterator<Item = Range<usize>>,
+    F: FnMut(&Range<usize>) -> K,
+    K: PartialEq,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeduplicateByIter")
+            .field("iter", &self.iter)
+            .field("key_fn", &DebugPlaceholder("<closure>"))
+            .field("key", &self.key.as_ref().map(|_| DebugPlaceholder("<key>")))
+            .field("coalesce", &self.coalesce)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`DeduplicateByIter`], which calls `key_fn` for every
+    /// [`Included`] range, and converts it into an [`Excluded`] range if
+    /// the key equals the key of the previous [`Included`] range.
+    /// Thereby only the first of consecutive [`Included`] ranges with
+    /// the same key is kept.
+    ///
+    /// Converted ranges are merged with their neighboring [`Excluded`] ranges.
+    ///
+    /// [`DeduplicateByIter`]: struct.DeduplicateByIter.html
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let text = "foo fob bar";
+    ///
+    /// let items = vec![0..3, 4..7, 8..11]
+    ///     .into_iter()
+    ///     .every_range(text.len())
+    ///     .deduplicate_included_by(|range| &text[range.start..(range.start + 2)])
+    ///     .map(|(kind, range)| (kind, &text[range]))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Included, "foo"), (Excluded, " fob "), (Included, "bar")]);
+    /// ```
+    #[inline]
+    pub fn deduplicate_included_by<F, K>(self, key_fn: F) -> DeduplicateByIter<I, F, K>
+    where
+        F: FnMut(&Range<usize>) -> K,
+        K: PartialEq,
+    {
+        DeduplicateByIter {
+            iter: self,
+            key_fn,
+            key: None,
+            coalesce: Coalesce::only(EveryRangeKind::Excluded),
+        }
+    }
+}
+
+impl<I, F, K> Iterator for DeduplicateByIter<I, F, K>
+where
+    I: Iterator<Item = Range<usize>>,
+    F: FnMut(&Range<usize>) -> K,
+    K: PartialEq,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (kind, range) in &mut self.iter {
+            let kind = match kind {
+                EveryRangeKind::Included => {
+                    let key = (self.key_fn)(&range);
+
+                    if self.key.as_ref() == Some(&key) {
+                        EveryRangeKind::Excluded
+                    } else {
+                        self.key = Some(key);
+                        EveryRangeKind::Included
+                    }
+                }
+                EveryRangeKind::Excluded => EveryRangeKind::Excluded,
+            };
+
+            if let Some(item) = self.coalesce.push((kind, range)) {
+                return Some(item);
+            }
+        }
+
+        self.coalesce.finish()
+    }
+}
+
+impl<I, F, K> FusedIterator for DeduplicateByIter<I, F, K>
+where
+    I: Iterator<Item = Range<usize>>,
+    F: FnMut(&Range<usize>) -> K,
+    K: PartialEq,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRangeKind::*;
+    use crate::{EveryRange, EveryRangeExt};
+
+    #[test]
+    fn dedup_by_range() {
+        let text = "Foo12Bar34Baz56";
+
+        let expected = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .collect::<Vec<_>>();
+
+        let actual = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .dedup_by_range()
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn dedup_by_range_zero_length() {
+        let ranges = [0..0, 0..0, 0..0, 3..3, 3..3];
+
+        let actual = ranges
+            .iter()
+            .cloned()
+            .every_range(5)
+            .dedup_by_range()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            [
+                (Included, 0..0),
+                (Excluded, 0..3),
+                (Included, 3..3),
+                (Excluded, 3..5),
+            ]
+        );
+
+        let actual = ranges
+            .iter()
+            .cloned()
+            .every_range(5)
+            .dedup_by_range_and_kind()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            [
+                (Included, 0..0),
+                (Excluded, 0..3),
+                (Included, 3..3),
+                (Excluded, 3..5),
+            ]
+        );
+    }
+
+    #[test]
+    fn deduplicate_included_by() {
+        let text = "Foo12Bar34Baz56";
+
+        let actual = text
+            .match_indices(char::is_alphabetic)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .deduplicate_included_by(|range| text[range.clone()].to_lowercase())
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Included, "F"),
+                (Included, "o"),
+                (Excluded, "o12"),
+                (Included, "B"),
+                (Included, "a"),
+                (Included, "r"),
+                (Excluded, "34"),
+                (Included, "B"),
+                (Included, "a"),
+                (Included, "z"),
+                (Excluded, "56"),
+            ]
+        );
+    }
+
+    #[test]
+    fn deduplicate_included_by_constant_key() {
+        let actual = [2..4, 6..8, 9..10]
+            .iter()
+            .cloned()
+            .every_range(12)
+            .deduplicate_included_by(|_| ())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [(Excluded, 0..2), (Included, 2..4), (Excluded, 4..12)]
+        );
+    }
+
+    #[test]
+    fn dedup_by_range_mapped() {
+        let items = || {
+            [2..4, 6..8]
+                .iter()
+                .cloned()
+                .every_range(10)
+                .map(|(kind, range)| (kind, range.start.min(5)..range.end.min(5)))
+        };
+
+        let actual = items().dedup_by_range().collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            [
+                (Excluded, 0..2),
+                (Included, 2..4),
+                (Excluded, 4..5),
+                (Included, 5..5),
+            ]
+        );
+
+        let actual = items().dedup_by_range_and_kind().collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            [
+                (Excluded, 0..2),
+                (Included, 2..4),
+                (Excluded, 4..5),
+                (Included, 5..5),
+                (Excluded, 5..5),
+            ]
+        );
+    }
+}
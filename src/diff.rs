@@ -0,0 +1,125 @@
+use std::ops::Range;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `TextDiff` holds the unchanged and changed regions
+/// of two aligned texts.
+///
+/// This `struct` is created by the [`into_text_diff`]
+/// method on [`EveryRangeIter`].
+///
+/// [`into_text_diff`]: struct.EveryRangeIter.html#method.into_text_diff
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+#[derive(Clone, Debug)]
+pub struct TextDiff<'a> {
+    old_text: &'a str,
+    new_text: &'a str,
+    items: Vec<(EveryRangeKind, Range<usize>)>,
+}
+
+impl<'a> TextDiff<'a> {
+    /// Returns an iterator over the unchanged regions, i.e. the
+    /// [`Included`] ranges, which are the same in both texts.
+    ///
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    #[inline]
+    pub fn unchanged_regions(&self) -> impl Iterator<Item = &'a str> + '_ {
+        let old_text = self.old_text;
+
+        self.ranges(EveryRangeKind::Included)
+            .map(move |range| &old_text[range])
+    }
+
+    /// Returns an iterator over the changed regions, i.e. the
+    /// [`Excluded`] ranges, as `(old, new)` pairs of substrings.
+    ///
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    #[inline]
+    pub fn changed_regions(&self) -> impl Iterator<Item = (&'a str, &'a str)> + '_ {
+        let (old_text, new_text) = (self.old_text, self.new_text);
+
+        self.ranges(EveryRangeKind::Excluded)
+            .map(move |range| (&old_text[range.clone()], &new_text[range]))
+    }
+
+    #[inline]
+    fn ranges(&self, kind: EveryRangeKind) -> impl Iterator<Item = Range<usize>> + '_ {
+        self.items
+            .iter()
+            .filter(move |(k, _)| *k == kind)
+            .map(|(_, range)| range.clone())
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Consume the `EveryRangeIter` and create a [`TextDiff`] of
+    /// `old_text` and `new_text`, where [`Included`] ranges are the
+    /// unchanged regions, and [`Excluded`] ranges are the changed regions.
+    ///
+    /// The ranges must be valid positions in both `old_text` and `new_text`,
+    /// i.e. the texts are aligned such as when comparing them byte by byte.
+    ///
+    /// [`TextDiff`]: struct.TextDiff.html
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Panics
+    ///
+    /// The methods of the returned [`TextDiff`] panic, if any range is out of
+    /// bounds, or not on a UTF-8 character boundary, of either text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::EveryRange;
+    ///
+    /// let old_text = "Foo Bar Baz";
+    /// let new_text = "Foo Qux Baz";
+    ///
+    /// let diff = vec![0..4, 7..11]
+    ///     .into_iter()
+    ///     .every_range(old_text.len())
+    ///     .into_text_diff(old_text, new_text);
+    ///
+    /// assert_eq!(diff.unchanged_regions().collect::<Vec<_>>(), ["Foo ", " Baz"]);
+    /// assert_eq!(diff.changed_regions().collect::<Vec<_>>(), [("Bar", "Qux")]);
+    /// ```
+    #[inline]
+    pub fn into_text_diff<'a>(self, old_text: &'a str, new_text: &'a str) -> TextDiff<'a> {
+        TextDiff {
+            old_text,
+            new_text,
+            items: self.collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+
+    #[test]
+    fn into_text_diff() {
+        let old_text = "Foo12Bar34Baz56";
+        let new_text = "Foo12Qux34Baz78";
+
+        let bytes = old_text.bytes().zip(new_text.bytes());
+        let unchanged = bytes
+            .enumerate()
+            .filter(|(_, (a, b))| a == b)
+            .map(|(i, _)| i..(i + 1));
+
+        let diff = unchanged
+            .every_range(old_text.len())
+            .into_text_diff(old_text, new_text);
+
+        assert_eq!(diff.unchanged_regions().collect::<String>(), "Foo1234Baz");
+        assert_eq!(
+            diff.changed_regions().collect::<Vec<_>>(),
+            [("Bar", "Qux"), ("56", "78")]
+        );
+    }
+}
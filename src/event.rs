@@ -0,0 +1,219 @@
+use std::fmt;
+use std::ops::Range;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `EventHandler` receives the items of an [`EventStream`],
+/// as callbacks for each kind of range.
+///
+/// `EventHandler` is implemented for `&mut H` where `H: EventHandler`,
+/// so a handler can be driven by reference and inspected afterwards.
+///
+/// [`EventStream`]: struct.EventStream.html
+pub trait EventHandler {
+    /// The error type which stops [`drive`] early.
+    ///
+    /// [`drive`]: struct.EventStream.html#method.drive
+    type Error;
+
+    /// Called for each [`Included`] range.
+    ///
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    fn on_included(&mut self, range: Range<usize>) -> Result<(), Self::Error>;
+
+    /// Called for each [`Excluded`] range.
+    ///
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    fn on_excluded(&mut self, range: Range<usize>) -> Result<(), Self::Error>;
+}
+
+impl<H> EventHandler for &mut H
+where
+    H: EventHandler + ?Sized,
+{
+    type Error = H::Error;
+
+    #[inline]
+    fn on_included(&mut self, range: Range<usize>) -> Result<(), Self::Error> {
+        (**self).on_included(range)
+    }
+
+    #[inline]
+    fn on_excluded(&mut self, range: Range<usize>) -> Result<(), Self::Error> {
+        (**self).on_excluded(range)
+    }
+}
+
+/// `EventStream` pushes the items of an [`EveryRangeIter`]
+/// to an [`EventHandler`].
+///
+/// This `struct` is created by the [`into_event_stream`]
+/// method on [`EveryRangeIter`].
+///
+/// [`into_event_stream`]: struct.EveryRangeIter.html#method.into_event_stream
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`EventHandler`]: trait.EventHandler.html
+pub struct EventStream<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+}
+
+impl<I> fmt::Debug for EventStream<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventStream")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I> EventStream<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Call [`on_included`] or [`on_excluded`] on `handler`
+    /// for each item, in order.
+    ///
+    /// If the handler returns an error, then `drive` stops
+    /// and returns that error.
+    ///
+    /// [`on_included`]: trait.EventHandler.html#tymethod.on_included
+    /// [`on_excluded`]: trait.EventHandler.html#tymethod.on_excluded
+    pub fn drive<H: EventHandler>(self, mut handler: H) -> Result<(), H::Error> {
+        for (kind, range) in self.iter {
+            match kind {
+                EveryRangeKind::Included => handler.on_included(range)?,
+                EveryRangeKind::Excluded => handler.on_excluded(range)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create an [`EventStream`], which converts the iterator into
+    /// a push-based model, where an [`EventHandler`] is called
+    /// for each item.
+    ///
+    /// [`EventStream`]: struct.EventStream.html
+    /// [`EventHandler`]: trait.EventHandler.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::ops::Range;
+    ///
+    /// use every_range::{EveryRange, EventHandler};
+    ///
+    /// struct Sizes(usize, usize);
+    ///
+    /// impl EventHandler for Sizes {
+    ///     type Error = ();
+    ///
+    ///     fn on_included(&mut self, range: Range<usize>) -> Result<(), ()> {
+    ///         self.0 += range.len();
+    ///         Ok(())
+    ///     }
+    ///
+    ///     fn on_excluded(&mut self, range: Range<usize>) -> Result<(), ()> {
+    ///         self.1 += range.len();
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mut sizes = Sizes(0, 0);
+    ///
+    /// vec![2..3, 6..9]
+    ///     .into_iter()
+    ///     .every_range(10)
+    ///     .into_event_stream()
+    ///     .drive(&mut sizes)
+    ///     .unwrap();
+    ///
+    /// assert_eq!((sizes.0, sizes.1), (4, 6));
+    /// ```
+    #[inline]
+    pub fn into_event_stream(self) -> EventStream<I> {
+        EventStream { iter: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[derive(Default)]
+    struct Recorder {
+        items: Vec<(EveryRangeKind, Range<usize>)>,
+        fail_at: Option<usize>,
+    }
+
+    impl EventHandler for Recorder {
+        type Error = usize;
+
+        fn on_included(&mut self, range: Range<usize>) -> Result<(), usize> {
+            if self.fail_at == Some(range.start) {
+                return Err(range.start);
+            }
+            self.items.push((Included, range));
+            Ok(())
+        }
+
+        fn on_excluded(&mut self, range: Range<usize>) -> Result<(), usize> {
+            self.items.push((Excluded, range));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn into_event_stream() {
+        let text = "Foo12Bar34Baz56";
+
+        let expected = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .collect::<Vec<_>>();
+
+        let mut recorder = Recorder::default();
+        text.match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .into_event_stream()
+            .drive(&mut recorder)
+            .unwrap();
+
+        assert_eq!(recorder.items, expected);
+    }
+
+    #[test]
+    fn into_event_stream_error() {
+        let mut recorder = Recorder {
+            fail_at: Some(6),
+            ..Recorder::default()
+        };
+
+        let res = [2..4, 6..8, 9..10]
+            .iter()
+            .cloned()
+            .every_range(10)
+            .into_event_stream()
+            .drive(&mut recorder);
+
+        assert_eq!(res, Err(6));
+        assert_eq!(
+            recorder.items,
+            [(Excluded, 0..2), (Included, 2..4), (Excluded, 4..6)]
+        );
+    }
+}
@@ -0,0 +1,511 @@
+use std::error;
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `EveryRangeError` is returned by [`TryEveryRangeIter`] in the
+/// conditions where [`EveryRangeIter`] would otherwise panic.
+///
+/// [`TryEveryRangeIter`]: struct.TryEveryRangeIter.html
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum EveryRangeError {
+    /// The range ends before the end of the previous range,
+    /// i.e. it was received out of order.
+    OutOfOrder {
+        /// The end of the previous range.
+        prev_end: usize,
+        /// The start of the offending range.
+        cur_start: usize,
+    },
+
+    /// The range starts before the end of the previous range,
+    /// but ends after it.
+    Overlapping {
+        /// The end of the previous range.
+        prev_end: usize,
+        /// The start of the offending range.
+        cur_start: usize,
+    },
+
+    /// The range exceeds the `end` of the iterator.
+    ExceedsEnd {
+        /// The end of the offending range.
+        range_end: usize,
+        /// The `end` of the iterator.
+        iter_end: usize,
+    },
+
+    /// The range starts after it ends.
+    Reversed {
+        /// The start of the offending range.
+        range_start: usize,
+        /// The end of the offending range.
+        range_end: usize,
+    },
+}
+
+impl fmt::Display for EveryRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::OutOfOrder {
+                prev_end,
+                cur_start,
+            } => write!(
+                f,
+                "range starting at {} is out of order, previous range ended at {}",
+                cur_start, prev_end
+            ),
+            Self::Overlapping {
+                prev_end,
+                cur_start,
+            } => write!(
+                f,
+                "range starting at {} overlaps previous range ending at {}",
+                cur_start, prev_end
+            ),
+            Self::ExceedsEnd {
+                range_end,
+                iter_end,
+            } => write!(f, "range ending at {} exceeds end {}", range_end, iter_end),
+            Self::Reversed {
+                range_start,
+                range_end,
+            } => write!(
+                f,
+                "range starting at {} ends before it at {}",
+                range_start, range_end
+            ),
+        }
+    }
+}
+
+impl error::Error for EveryRangeError {}
+
+/// `TryEveryRangeIter` is an [`EveryRangeIter`], which returns an
+/// [`EveryRangeError`] instead of panicking.
+///
+/// This `struct` is created by the [`try_every_range`]
+/// method on [`TryEveryRange`].
+///
+/// [`try_every_range`]: trait.TryEveryRange.html#method.try_every_range
+/// [`TryEveryRange`]: trait.TryEveryRange.html
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`EveryRangeError`]: enum.EveryRangeError.html
+pub struct TryEveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+    failed: bool,
+}
+
+impl<I> fmt::Debug for TryEveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryEveryRangeIter")
+            .field("iter", &self.iter)
+            .field("failed", &self.failed)
+            .finish()
+    }
+}
+
+/// `CheckedBoundsIter` is a [`TryEveryRangeIter`], which returns an
+/// [`EveryRangeError`] instead of panicking.
+///
+/// This type is created by the [`check_bounds`]
+/// method on [`EveryRangeIter`].
+///
+/// [`check_bounds`]: struct.EveryRangeIter.html#method.check_bounds
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`TryEveryRangeIter`]: struct.TryEveryRangeIter.html
+/// [`EveryRangeError`]: enum.EveryRangeError.html
+pub type CheckedBoundsIter<I> = TryEveryRangeIter<I>;
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`CheckedBoundsIter`], which yields the same items as
+    /// the `EveryRangeIter`, but wrapped in `Ok`. In the conditions where
+    /// the `EveryRangeIter` panics, an [`EveryRangeError`] is returned
+    /// instead, after which the iterator is exhausted.
+    ///
+    /// *See also [`try_every_range`].*
+    ///
+    /// [`CheckedBoundsIter`]: type.CheckedBoundsIter.html
+    /// [`EveryRangeError`]: enum.EveryRangeError.html
+    /// [`try_every_range`]: trait.TryEveryRange.html#method.try_every_range
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeError, EveryRangeKind::*};
+    ///
+    /// let mut iter = vec![2..4, 6..12].into_iter().every_range(10).check_bounds();
+    ///
+    /// assert_eq!(iter.next(), Some(Ok((Excluded, 0..2))));
+    /// assert_eq!(iter.next(), Some(Ok((Included, 2..4))));
+    /// assert_eq!(
+    ///     iter.next(),
+    ///     Some(Err(EveryRangeError::ExceedsEnd {
+    ///         range_end: 12,
+    ///         iter_end: 10,
+    ///     }))
+    /// );
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn check_bounds(self) -> CheckedBoundsIter<I> {
+        TryEveryRangeIter {
+            iter: self,
+            failed: false,
+        }
+    }
+}
+
+/// Validate `range` against the end of the previous range,
+/// and the `end` of the iterator.
+///
+/// This is the single classification of invalid ranges, shared by all
+/// adapters that handle them instead of panicking. The conditions are
+/// checked in the same order as `EveryRangeIter` asserts them.
+pub(crate) fn check_range(
+    prev_end: usize,
+    end: usize,
+    range: &Range<usize>,
+) -> Result<(), EveryRangeError> {
+    if range.start < prev_end {
+        if range.end <= prev_end {
+            Err(EveryRangeError::OutOfOrder {
+                prev_end,
+                cur_start: range.start,
+            })
+        } else {
+            Err(EveryRangeError::Overlapping {
+                prev_end,
+                cur_start: range.start,
+            })
+        }
+    } else if range.end > end {
+        Err(EveryRangeError::ExceedsEnd {
+            range_end: range.end,
+            iter_end: end,
+        })
+    } else if range.start > range.end {
+        Err(EveryRangeError::Reversed {
+            range_start: range.start,
+            range_end: range.end,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Validate that `ranges` are sorted, non-overlapping, and do not exceed
+/// `end`, i.e. that [`every_range`] would not panic.
+///
+/// Returns the [`EveryRangeError`] of the first invalid range.
+///
+/// [`every_range`]: trait.EveryRange.html#method.every_range
+/// [`EveryRangeError`]: enum.EveryRangeError.html
+///
+/// # Example
+///
+/// ```
+/// use every_range::{validate_ranges, EveryRangeError};
+///
+/// assert_eq!(validate_ranges(&[0..2, 4..6], 10), Ok(()));
+/// assert_eq!(
+///     validate_ranges(&[0..2, 4..6], 5),
+///     Err(EveryRangeError::ExceedsEnd {
+///         range_end: 6,
+///         iter_end: 5,
+///     })
+/// );
+/// ```
+pub fn validate_ranges(ranges: &[Range<usize>], end: usize) -> Result<(), EveryRangeError> {
+    let mut prev_end = 0;
+
+    for range in ranges {
+        check_range(prev_end, end, range)?;
+        prev_end = range.end;
+    }
+
+    Ok(())
+}
+
+impl<I> Iterator for TryEveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = Result<(EveryRangeKind, Range<usize>), EveryRangeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        if self.iter.next.is_none() {
+            let range = self.iter.iter.next().or_else(|| self.iter.back.take());
+
+            if let Some(range) = range {
+                // Anything past `back_index` was already consumed from the back
+                if let Err(err) = check_range(self.iter.index, self.iter.back_index, &range) {
+                    self.failed = true;
+                    return Some(Err(err));
+                }
+
+                self.iter.next = Some(range);
+            }
+        }
+
+        self.iter.next().map(Ok)
+    }
+}
+
+impl<I> FusedIterator for TryEveryRangeIter<I> where I: Iterator<Item = Range<usize>> {}
+
+/// Trait which implements `try_every_range` to get a `TryEveryRangeIter`.
+///
+/// *[See `EveryRangeIter` for more information.][`EveryRangeIter`]*
+///
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub trait TryEveryRange: Sized + Iterator<Item = Range<usize>> {
+    /// Create a [`TryEveryRangeIter`] with `end`, which yields the same
+    /// items as [`every_range`], but wrapped in `Ok`. In the conditions
+    /// where [`EveryRangeIter`] panics, an [`EveryRangeError`] is returned
+    /// instead, after which the iterator is exhausted.
+    ///
+    /// [`TryEveryRangeIter`]: struct.TryEveryRangeIter.html
+    /// [`every_range`]: trait.EveryRange.html#method.every_range
+    /// [`EveryRangeIter`]: struct.EveryRangeIter.html
+    /// [`EveryRangeError`]: enum.EveryRangeError.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRangeError, EveryRangeKind::*, TryEveryRange};
+    ///
+    /// let mut iter = vec![2..4, 3..6].into_iter().try_every_range(10);
+    ///
+    /// assert_eq!(iter.next(), Some(Ok((Excluded, 0..2))));
+    /// assert_eq!(iter.next(), Some(Ok((Included, 2..4))));
+    /// assert_eq!(
+    ///     iter.next(),
+    ///     Some(Err(EveryRangeError::Overlapping {
+    ///         prev_end: 4,
+    ///         cur_start: 3,
+    ///     }))
+    /// );
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn try_every_range(self, end: usize) -> TryEveryRangeIter<Self> {
+        EveryRangeIter::new(self, end).check_bounds()
+    }
+}
+
+impl<T> TryEveryRange for T where T: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EveryRange;
+
+    fn try_collect<I>(
+        ranges: I,
+        end: usize,
+    ) -> Result<Vec<(EveryRangeKind, Range<usize>)>, EveryRangeError>
+    where
+        I: Iterator<Item = Range<usize>>,
+    {
+        ranges.try_every_range(end).collect()
+    }
+
+    #[test]
+    fn try_every_range_ok() {
+        let text = "Foo12Bar34Baz56";
+
+        let ranges = || {
+            text.match_indices(char::is_numeric)
+                .map(|(start, part)| start..(start + part.len()))
+        };
+
+        let expected = ranges().every_range(text.len()).collect::<Vec<_>>();
+        assert_eq!(try_collect(ranges(), text.len()), Ok(expected));
+    }
+
+    #[test]
+    fn range_start_after_end() {
+        assert_eq!(
+            try_collect([0..2, 4..6].iter().cloned(), 3),
+            Err(EveryRangeError::ExceedsEnd {
+                range_end: 6,
+                iter_end: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn range_end_after_end() {
+        assert_eq!(
+            try_collect([0..2, 4..6].iter().cloned(), 5),
+            Err(EveryRangeError::ExceedsEnd {
+                range_end: 6,
+                iter_end: 5,
+            })
+        );
+    }
+
+    #[test]
+    fn range_start_after_index() {
+        assert_eq!(
+            try_collect([0..4, 2..6].iter().cloned(), 5),
+            Err(EveryRangeError::Overlapping {
+                prev_end: 4,
+                cur_start: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn ranges_out_of_order() {
+        let cases = [
+            ([4..6, 0..2, 8..10], 6, 0),
+            ([8..10, 0..2, 4..6], 10, 0),
+            ([0..2, 8..10, 4..6], 10, 4),
+            ([4..6, 8..10, 0..2], 10, 0),
+            ([8..10, 4..6, 0..2], 10, 4),
+        ];
+
+        for (ranges, prev_end, cur_start) in cases.iter().cloned() {
+            assert_eq!(
+                try_collect(ranges.iter().cloned(), 20),
+                Err(EveryRangeError::OutOfOrder {
+                    prev_end,
+                    cur_start,
+                })
+            );
+        }
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn range_reversed() {
+        assert_eq!(
+            try_collect([0..2, 6..4].iter().cloned(), 10),
+            Err(EveryRangeError::Reversed {
+                range_start: 6,
+                range_end: 4,
+            })
+        );
+    }
+
+    #[test]
+    fn try_every_range_exhausted_after_error() {
+        let mut iter = [4..6, 0..2, 8..10].iter().cloned().try_every_range(20);
+
+        assert_eq!(iter.next(), Some(Ok((EveryRangeKind::Excluded, 0..4))));
+        assert_eq!(iter.next(), Some(Ok((EveryRangeKind::Included, 4..6))));
+        assert!(matches!(iter.next(), Some(Err(_))));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn check_bounds_partially_consumed() {
+        let mut iter = [2..4, 3..6].iter().cloned().every_range(10);
+
+        assert_eq!(iter.next(), Some((EveryRangeKind::Excluded, 0..2)));
+
+        let mut iter = iter.check_bounds();
+        assert_eq!(iter.next(), Some(Ok((EveryRangeKind::Included, 2..4))));
+        assert_eq!(
+            iter.next(),
+            Some(Err(EveryRangeError::Overlapping {
+                prev_end: 4,
+                cur_start: 3,
+            }))
+        );
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn validate_ranges() {
+        use super::validate_ranges;
+
+        let text = "Foo12Bar34Baz56";
+        let ranges = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(validate_ranges(&ranges, text.len()), Ok(()));
+        assert_eq!(validate_ranges(&[], 0), Ok(()));
+
+        assert_eq!(
+            validate_ranges(&[4..6, 0..2, 8..10], 20),
+            Err(EveryRangeError::OutOfOrder {
+                prev_end: 6,
+                cur_start: 0,
+            })
+        );
+        assert_eq!(
+            validate_ranges(&[0..4, 2..6, 20..30], 10),
+            Err(EveryRangeError::Overlapping {
+                prev_end: 4,
+                cur_start: 2,
+            })
+        );
+        assert_eq!(
+            validate_ranges(&[0..2, 4..6, 1..2], 5),
+            Err(EveryRangeError::ExceedsEnd {
+                range_end: 6,
+                iter_end: 5,
+            })
+        );
+        assert_eq!(
+            validate_ranges(&[0..2, 6..4], 10),
+            Err(EveryRangeError::Reversed {
+                range_start: 6,
+                range_end: 4,
+            })
+        );
+    }
+
+    #[test]
+    fn check_bounds_consumed_from_back() {
+        use EveryRangeKind::*;
+
+        let mut iter = vec![2..4, 6..8].into_iter().every_range(10);
+        assert_eq!(iter.next_back(), Some((Excluded, 8..10)));
+
+        let actual = iter.check_bounds().collect::<Result<Vec<_>, _>>();
+        assert_eq!(
+            actual,
+            Ok(vec![
+                (Excluded, 0..2),
+                (Included, 2..4),
+                (Excluded, 4..6),
+                (Included, 6..8),
+            ])
+        );
+
+        let mut iter = vec![2..4, 5..9, 6..8].into_iter().every_range(10);
+        assert_eq!(iter.next_back(), Some((Excluded, 8..10)));
+
+        let actual = iter.check_bounds().collect::<Result<Vec<_>, _>>();
+        assert_eq!(
+            actual,
+            Err(EveryRangeError::ExceedsEnd {
+                range_end: 9,
+                iter_end: 8,
+            })
+        );
+    }
+}
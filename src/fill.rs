@@ -0,0 +1,171 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{DebugPlaceholder, EveryRangeIter, EveryRangeKind};
+
+/// `FillGapsIter` transforms every [`Excluded`] item with a closure.
+///
+/// This `struct` is created by the [`fill_gaps_with`]
+/// method on [`EveryRangeIter`].
+///
+/// [`fill_gaps_with`]: struct.EveryRangeIter.html#method.fill_gaps_with
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+pub struct FillGapsIter<I, F>
+where
+    I: Iterator<Item = Range<usize>>,
+    F: FnMut(Range<usize>) -> (EveryRangeKind, Range<usize>),
+{
+    iter: EveryRangeIter<I>,
+    gap_producer: F,
+}
+
+impl<I, F> fmt::Debug for FillGapsIter<I, F>
+where
+    I: Iterator<Item = Range<usize>>,
+    F: FnMut(Range<usize>) -> (EveryRangeKind, Range<usize>),
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FillGapsIter")
+            .field("iter", &self.iter)
+            .field("gap_producer", &DebugPlaceholder("<closure>"))
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`FillGapsIter`], which calls `gap_producer` with the range
+    /// of every [`Excluded`] item, and yields the returned item instead.
+    ///
+    /// The closure is free to return another kind, e.g. promoting a gap to
+    /// [`Included`], or another range. [`Included`] items are yielded as is.
+    ///
+    /// See also [`interleave_gaps`] for taking the gaps from an iterator.
+    ///
+    /// [`FillGapsIter`]: struct.FillGapsIter.html
+    /// [`interleave_gaps`]: struct.EveryRangeIter.html#method.interleave_gaps
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![2..4, 5..8]
+    ///     .into_iter()
+    ///     .every_range(10)
+    ///     .fill_gaps_with(|gap| {
+    ///         if gap.len() == 1 {
+    ///             (Included, gap)
+    ///         } else {
+    ///             (Excluded, gap)
+    ///         }
+    ///     })
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     items,
+    ///     [
+    ///         (Excluded, 0..2),
+    ///         (Included, 2..4),
+    ///         (Included, 4..5),
+    ///         (Included, 5..8),
+    ///         (Excluded, 8..10),
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    pub fn fill_gaps_with<F>(self, gap_producer: F) -> FillGapsIter<I, F>
+    where
+        F: FnMut(Range<usize>) -> (EveryRangeKind, Range<usize>),
+    {
+        FillGapsIter {
+            iter: self,
+            gap_producer,
+        }
+    }
+}
+
+impl<I, F> Iterator for FillGapsIter<I, F>
+where
+    I: Iterator<Item = Range<usize>>,
+    F: FnMut(Range<usize>) -> (EveryRangeKind, Range<usize>),
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next()? {
+            (EveryRangeKind::Excluded, range) => Some((self.gap_producer)(range)),
+            item => Some(item),
+        }
+    }
+}
+
+impl<I, F> FusedIterator for FillGapsIter<I, F>
+where
+    I: Iterator<Item = Range<usize>>,
+    F: FnMut(Range<usize>) -> (EveryRangeKind, Range<usize>),
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn fill_gaps_with() {
+        let text = "Foo12Bar34Baz56";
+
+        let mut gaps = Vec::new();
+        let actual = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .fill_gaps_with(|gap| {
+                gaps.push(gap.clone());
+                (Included, gap)
+            })
+            .map(|(kind, _)| kind)
+            .collect::<Vec<_>>();
+
+        assert!(actual.iter().all(|&kind| kind == Included));
+        assert_eq!(gaps, [0..3, 5..8, 10..13]);
+    }
+
+    #[test]
+    fn fill_gaps_with_debug() {
+        let iter = std::iter::once(2..4)
+            .every_range(6)
+            .fill_gaps_with(|gap| (Included, gap));
+
+        assert_eq!(
+            format!("{:?}", iter),
+            "FillGapsIter { \
+             iter: EveryRangeIter { index: 0, end: 6, iter: <iterator>, next: None, \
+             back_index: 6, back: None, skip_trailing: false }, \
+             gap_producer: <closure> }"
+        );
+    }
+
+    #[test]
+    fn fill_gaps_with_identity() {
+        let ranges = [2..4, 6..8];
+
+        let actual = ranges
+            .iter()
+            .cloned()
+            .every_range(10)
+            .fill_gaps_with(|gap| (Excluded, gap))
+            .collect::<Vec<_>>();
+        let expected = ranges.iter().cloned().every_range(10).collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+}
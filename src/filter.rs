@@ -0,0 +1,342 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `KindFilterIter` only yields items of the enabled kinds.
+///
+/// This `struct` is created by the [`with_kind_filter`]
+/// method on [`EveryRangeIter`].
+///
+/// [`with_kind_filter`]: struct.EveryRangeIter.html#method.with_kind_filter
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct KindFilterIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+    include_included: bool,
+    include_excluded: bool,
+}
+
+impl<I> fmt::Debug for KindFilterIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KindFilterIter")
+            .field("iter", &self.iter)
+            .field("include_included", &self.include_included)
+            .field("include_excluded", &self.include_excluded)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`KindFilterIter`], which only yields [`Included`] items
+    /// if `include_included` is `true`, and only yields [`Excluded`]
+    /// items if `include_excluded` is `true`.
+    ///
+    /// [`KindFilterIter`]: struct.KindFilterIter.html
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![2..4, 6..8]
+    ///     .into_iter()
+    ///     .every_range(10)
+    ///     .with_kind_filter(false, true)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Excluded, 0..2), (Excluded, 4..6), (Excluded, 8..10)]);
+    /// ```
+    #[inline]
+    pub fn with_kind_filter(
+        self,
+        include_included: bool,
+        include_excluded: bool,
+    ) -> KindFilterIter<I> {
+        KindFilterIter {
+            iter: self,
+            include_included,
+            include_excluded,
+        }
+    }
+}
+
+impl<I> Iterator for KindFilterIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.include_included && !self.include_excluded {
+            return None;
+        }
+
+        let (include_included, include_excluded) = (self.include_included, self.include_excluded);
+        self.iter.find(|(kind, _)| match kind {
+            EveryRangeKind::Included => include_included,
+            EveryRangeKind::Excluded => include_excluded,
+        })
+    }
+}
+
+impl<I> FusedIterator for KindFilterIter<I> where I: Iterator<Item = Range<usize>> {}
+
+/// `FilterKindIter` only yields the ranges of a single kind,
+/// without the kind.
+///
+/// This `struct` is created by the [`filter_included`] and
+/// [`filter_excluded`] methods on [`EveryRangeIter`].
+///
+/// [`filter_included`]: struct.EveryRangeIter.html#method.filter_included
+/// [`filter_excluded`]: struct.EveryRangeIter.html#method.filter_excluded
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct FilterKindIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+    kind: EveryRangeKind,
+}
+
+impl<I> fmt::Debug for FilterKindIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FilterKindIter")
+            .field("iter", &self.iter)
+            .field("kind", &self.kind)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`FilterKindIter`], which only yields the
+    /// ranges of the [`Included`] items.
+    ///
+    /// [`FilterKindIter`]: struct.FilterKindIter.html
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::EveryRange;
+    ///
+    /// let ranges = vec![2..4, 6..8]
+    ///     .into_iter()
+    ///     .every_range(10)
+    ///     .filter_included()
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(ranges, [2..4, 6..8]);
+    /// ```
+    #[inline]
+    pub fn filter_included(self) -> FilterKindIter<I> {
+        FilterKindIter {
+            iter: self,
+            kind: EveryRangeKind::Included,
+        }
+    }
+
+    /// Create a [`FilterKindIter`], which only yields the
+    /// ranges of the [`Excluded`] items.
+    ///
+    /// [`FilterKindIter`]: struct.FilterKindIter.html
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::EveryRange;
+    ///
+    /// let ranges = vec![2..4, 6..8]
+    ///     .into_iter()
+    ///     .every_range(10)
+    ///     .filter_excluded()
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(ranges, [0..2, 4..6, 8..10]);
+    /// ```
+    #[inline]
+    pub fn filter_excluded(self) -> FilterKindIter<I> {
+        FilterKindIter {
+            iter: self,
+            kind: EveryRangeKind::Excluded,
+        }
+    }
+}
+
+impl<I> Iterator for FilterKindIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = Range<usize>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let kind = self.kind;
+        self.iter.find(|&(k, _)| k == kind).map(|(_, range)| range)
+    }
+}
+
+impl<I> FusedIterator for FilterKindIter<I> where I: Iterator<Item = Range<usize>> {}
+
+/// `NonEmptyIter` is an [`EveryRangeIter`], which skips all zero-length ranges.
+///
+/// This `struct` is created by the [`every_range_non_empty`]
+/// method on [`EveryRange`].
+///
+/// [`every_range_non_empty`]: trait.EveryRange.html#method.every_range_non_empty
+/// [`EveryRange`]: trait.EveryRange.html
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct NonEmptyIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+}
+
+impl<I> fmt::Debug for NonEmptyIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NonEmptyIter")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I> NonEmptyIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    #[inline]
+    pub(crate) fn new(ranges: I, end: usize) -> Self {
+        Self {
+            iter: EveryRangeIter::new(ranges, end),
+        }
+    }
+}
+
+impl<I> Iterator for NonEmptyIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.find(|(_, range)| range.start != range.end)
+    }
+}
+
+impl<I> FusedIterator for NonEmptyIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn with_kind_filter() {
+        let text = "Foo12Bar34Baz56";
+
+        let filter = |include_included, include_excluded| {
+            text.match_indices(char::is_numeric)
+                .map(|(start, part)| start..(start + part.len()))
+                .every_range(text.len())
+                .with_kind_filter(include_included, include_excluded)
+                .map(|(kind, range)| (kind, &text[range]))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(filter(true, true).len(), 9);
+        assert_eq!(
+            filter(true, false),
+            [
+                (Included, "1"),
+                (Included, "2"),
+                (Included, "3"),
+                (Included, "4"),
+                (Included, "5"),
+                (Included, "6"),
+            ]
+        );
+        assert_eq!(
+            filter(false, true),
+            [(Excluded, "Foo"), (Excluded, "Bar"), (Excluded, "Baz")]
+        );
+        assert_eq!(filter(false, false), []);
+    }
+
+    #[test]
+    fn filter_kind() {
+        let text = "Foo12Bar34Baz56";
+
+        let every_range = || {
+            text.match_indices(char::is_numeric)
+                .map(|(start, part)| start..(start + part.len()))
+                .every_range(text.len())
+        };
+
+        let included = every_range()
+            .filter_included()
+            .map(|range| &text[range])
+            .collect::<Vec<_>>();
+        assert_eq!(included, ["1", "2", "3", "4", "5", "6"]);
+
+        let excluded = every_range()
+            .filter_excluded()
+            .map(|range| &text[range])
+            .collect::<Vec<_>>();
+        assert_eq!(excluded, ["Foo", "Bar", "Baz"]);
+    }
+
+    #[test]
+    fn every_range_non_empty() {
+        let ranges = [0..0, 2..4, 4..6, 6..6, 8..8, 8..10, 10..10];
+
+        let actual = ranges
+            .iter()
+            .cloned()
+            .every_range_non_empty(10)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            [
+                (Excluded, 0..2),
+                (Included, 2..4),
+                (Included, 4..6),
+                (Excluded, 6..8),
+                (Included, 8..10),
+            ]
+        );
+        assert!(actual.iter().all(|(_, range)| !range.is_empty()));
+
+        let text = "Foo12Bar34Baz56";
+        let ranges = || {
+            text.match_indices(char::is_numeric)
+                .map(|(start, part)| start..(start + part.len()))
+        };
+        assert!(ranges()
+            .every_range_non_empty(text.len())
+            .eq(ranges().every_range(text.len())));
+    }
+}
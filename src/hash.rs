@@ -0,0 +1,211 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::hash::Hasher;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{DebugPlaceholder, EveryRangeIter, EveryRangeKind};
+
+/// Feed `kind`, `range.start` and `range.end` into `hasher`.
+#[inline]
+pub(crate) fn write_item<H: Hasher>(hasher: &mut H, kind: EveryRangeKind, range: &Range<usize>) {
+    hasher.write_u8(match kind {
+        EveryRangeKind::Included => 0,
+        EveryRangeKind::Excluded => 1,
+    });
+    hasher.write_usize(range.start);
+    hasher.write_usize(range.end);
+}
+
+/// `ChecksummedIter` maintains a running checksum of the yielded items.
+///
+/// This `struct` is created by the [`with_running_checksum`]
+/// method on [`EveryRangeIter`].
+///
+/// [`with_running_checksum`]: struct.EveryRangeIter.html#method.with_running_checksum
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct ChecksummedIter<I, H>
+where
+    I: Iterator<Item = Range<usize>>,
+    H: Hasher,
+{
+    iter: EveryRangeIter<I>,
+    hasher: H,
+}
+
+impl<I, H> fmt::Debug for ChecksummedIter<I, H>
+where
+    I: Iterator<Item = Range<usize>>,
+    H: Hasher,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChecksummedIter")
+            .field("iter", &self.iter)
+            .field("hasher", &DebugPlaceholder("<hasher>"))
+            .finish()
+    }
+}
+
+impl<I, H> ChecksummedIter<I, H>
+where
+    I: Iterator<Item = Range<usize>>,
+    H: Hasher,
+{
+    /// Returns the checksum of the items yielded so far.
+    #[inline]
+    pub fn current_hash(&self) -> u64 {
+        self.hasher.finish()
+    }
+
+    /// Consume the `ChecksummedIter` and return the checksum
+    /// of the items yielded so far.
+    ///
+    /// *Note that the remaining items are not yielded, i.e.
+    /// they are not included in the checksum.*
+    #[inline]
+    pub fn finalize(self) -> u64 {
+        self.hasher.finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`ChecksummedIter`], which passes the kind, start,
+    /// and end of every yielded item through `hasher`.
+    ///
+    /// The checksum of all the items yielded so far, is available
+    /// through [`current_hash`]. Hashing all items with
+    /// [`DefaultHasher::new()`], produces the same checksum
+    /// as [`hash_ranges`].
+    ///
+    /// [`ChecksummedIter`]: struct.ChecksummedIter.html
+    /// [`current_hash`]: struct.ChecksummedIter.html#method.current_hash
+    /// [`hash_ranges`]: struct.EveryRangeIter.html#method.hash_ranges
+    /// [`DefaultHasher::new()`]: https://doc.rust-lang.org/stable/std/collections/hash_map/struct.DefaultHasher.html#method.new
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use every_range::EveryRange;
+    ///
+    /// let ranges = vec![2..4, 6..8];
+    ///
+    /// let prefix = ranges.clone().into_iter().every_range(10);
+    /// let mut prefix = prefix.with_running_checksum(DefaultHasher::new());
+    /// prefix.by_ref().take(2).for_each(|_| {});
+    ///
+    /// let iter = ranges.into_iter().every_range(10);
+    /// let mut iter = iter.with_running_checksum(DefaultHasher::new());
+    /// iter.next();
+    /// iter.next();
+    ///
+    /// assert_eq!(iter.current_hash(), prefix.finalize());
+    /// ```
+    #[inline]
+    pub fn with_running_checksum<H: Hasher>(self, hasher: H) -> ChecksummedIter<I, H> {
+        ChecksummedIter { iter: self, hasher }
+    }
+
+    /// Consume the `EveryRangeIter` and hash the whole sequence
+    /// of `(kind, start, end)` triples into a single `u64`.
+    ///
+    /// The hash is computed using [`DefaultHasher`] with fixed keys,
+    /// so the same sequence always produces the same hash. However,
+    /// the hash is not guaranteed to be stable across Rust releases.
+    ///
+    /// [`DefaultHasher`]: https://doc.rust-lang.org/stable/std/collections/hash_map/struct.DefaultHasher.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::EveryRange;
+    ///
+    /// let a = vec![2..4, 6..8].into_iter().every_range(10).hash_ranges();
+    /// let b = vec![2..4, 6..8].into_iter().every_range(10).hash_ranges();
+    /// let c = vec![2..4, 6..9].into_iter().every_range(10).hash_ranges();
+    ///
+    /// assert_eq!(a, b);
+    /// assert_ne!(a, c);
+    /// ```
+    pub fn hash_ranges(self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+
+        for (kind, range) in self {
+            write_item(&mut hasher, kind, &range);
+        }
+
+        hasher.finish()
+    }
+}
+
+impl<I, H> Iterator for ChecksummedIter<I, H>
+where
+    I: Iterator<Item = Range<usize>>,
+    H: Hasher,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (kind, range) = self.iter.next()?;
+        write_item(&mut self.hasher, kind, &range);
+        Some((kind, range))
+    }
+}
+
+impl<I, H> FusedIterator for ChecksummedIter<I, H>
+where
+    I: Iterator<Item = Range<usize>>,
+    H: Hasher,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EveryRange;
+
+    #[test]
+    fn hash_ranges() {
+        let hash = |ranges: &[std::ops::Range<usize>], end| {
+            ranges.iter().cloned().every_range(end).hash_ranges()
+        };
+
+        assert_eq!(hash(&[3..5, 8..10], 15), hash(&[3..5, 8..10], 15));
+
+        // Same included ranges, but different trailing excluded range
+        assert_ne!(hash(&[3..5, 8..10], 15), hash(&[3..5, 8..10], 10));
+        assert_ne!(hash(&[3..5, 8..10], 15), hash(&[3..5, 8..11], 15));
+        assert_ne!(hash(&[0..3, 3..5], 5), hash(&[], 5));
+    }
+
+    #[test]
+    fn with_running_checksum() {
+        let ranges = [3..5, 8..10];
+
+        let mut iter = ranges
+            .iter()
+            .cloned()
+            .every_range(15)
+            .with_running_checksum(DefaultHasher::new());
+
+        let mut hashes = vec![iter.current_hash()];
+        while iter.next().is_some() {
+            hashes.push(iter.current_hash());
+        }
+        assert_eq!(hashes.len(), 6);
+
+        // Every prefix produces a distinct hash
+        for (i, a) in hashes.iter().enumerate() {
+            for b in &hashes[(i + 1)..] {
+                assert_ne!(a, b);
+            }
+        }
+
+        let hash = ranges.iter().cloned().every_range(15).hash_ranges();
+        assert_eq!(iter.finalize(), hash);
+    }
+}
@@ -0,0 +1,251 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::{Range, RangeInclusive};
+
+use crate::{DebugPlaceholder, EveryRangeIter};
+
+/// `InclusiveRanges` converts [`RangeInclusive`]s into half-open
+/// [`Range`]s, i.e. `start..=end` into `start..(end + 1)`.
+///
+/// This `struct` is used by [`EveryRangeInclusiveIter`], which is created
+/// by the [`every_range_inclusive`] method on [`EveryRangeInclusive`].
+///
+/// [`Range`]: https://doc.rust-lang.org/stable/std/ops/struct.Range.html
+/// [`RangeInclusive`]: https://doc.rust-lang.org/stable/std/ops/struct.RangeInclusive.html
+/// [`EveryRangeInclusiveIter`]: type.EveryRangeInclusiveIter.html
+/// [`every_range_inclusive`]: trait.EveryRangeInclusive.html#method.every_range_inclusive
+/// [`EveryRangeInclusive`]: trait.EveryRangeInclusive.html
+pub struct InclusiveRanges<I>
+where
+    I: Iterator<Item = RangeInclusive<usize>>,
+{
+    iter: I,
+}
+
+impl<I> fmt::Debug for InclusiveRanges<I>
+where
+    I: Iterator<Item = RangeInclusive<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InclusiveRanges")
+            .field("iter", &DebugPlaceholder("<iterator>"))
+            .finish()
+    }
+}
+
+impl<I> Iterator for InclusiveRanges<I>
+where
+    I: Iterator<Item = RangeInclusive<usize>>,
+{
+    type Item = Range<usize>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let range = self.iter.next()?;
+
+        let start = *range.start();
+        if range.is_empty() {
+            Some(start..start)
+        } else {
+            Some(start..(*range.end() + 1))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> FusedIterator for InclusiveRanges<I> where I: FusedIterator<Item = RangeInclusive<usize>> {}
+
+/// `EveryRangeInclusiveIter` is an [`EveryRangeIter`] over [`RangeInclusive`]s.
+///
+/// The yielded ranges are half-open [`Range`]s like for any other
+/// [`EveryRangeIter`], and the same panic conditions apply to the
+/// converted ranges.
+///
+/// This type is created by the [`every_range_inclusive`]
+/// method on [`EveryRangeInclusive`].
+///
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`Range`]: https://doc.rust-lang.org/stable/std/ops/struct.Range.html
+/// [`RangeInclusive`]: https://doc.rust-lang.org/stable/std/ops/struct.RangeInclusive.html
+/// [`every_range_inclusive`]: trait.EveryRangeInclusive.html#method.every_range_inclusive
+/// [`EveryRangeInclusive`]: trait.EveryRangeInclusive.html
+pub type EveryRangeInclusiveIter<I> = EveryRangeIter<InclusiveRanges<I>>;
+
+/// Trait which implements `every_range_inclusive` to get
+/// an `EveryRangeInclusiveIter`.
+///
+/// *[See `EveryRangeIter` for more information.][`EveryRangeIter`]*
+///
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub trait EveryRangeInclusive: Sized + Iterator<Item = RangeInclusive<usize>> {
+    /// Create an [`EveryRangeInclusiveIter`] with `end`, where each
+    /// `start..=end` is converted into `start..(end + 1)`.
+    ///
+    /// `end` is exclusive, the same as for [`every_range`].
+    ///
+    /// [`EveryRangeInclusiveIter`]: type.EveryRangeInclusiveIter.html
+    /// [`every_range`]: trait.EveryRange.html#method.every_range
+    ///
+    /// # Panics
+    ///
+    /// Panics if any range ends at `usize::MAX`, as it cannot be converted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRangeInclusive, EveryRangeKind::*};
+    ///
+    /// let items = vec![2..=3, 6..=7]
+    ///     .into_iter()
+    ///     .every_range_inclusive(10)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     items,
+    ///     [
+    ///         (Excluded, 0..2),
+    ///         (Included, 2..4),
+    ///         (Excluded, 4..6),
+    ///         (Included, 6..8),
+    ///         (Excluded, 8..10),
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    fn every_range_inclusive(self, end: usize) -> EveryRangeInclusiveIter<Self> {
+        EveryRangeIter::new(InclusiveRanges { iter: self }, end)
+    }
+}
+
+impl<T> EveryRangeInclusive for T where T: Iterator<Item = RangeInclusive<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EveryRangeKind;
+
+    #[test]
+    fn every_range_inclusive_matches1() {
+        let text = "Foo12Bar34Baz56";
+
+        use EveryRangeKind::*;
+        let expected = [
+            ((Included, 0..1), "F"),
+            ((Included, 1..2), "o"),
+            ((Included, 2..3), "o"),
+            ((Excluded, 3..5), "12"),
+            ((Included, 5..6), "B"),
+            ((Included, 6..7), "a"),
+            ((Included, 7..8), "r"),
+            ((Excluded, 8..10), "34"),
+            ((Included, 10..11), "B"),
+            ((Included, 11..12), "a"),
+            ((Included, 12..13), "z"),
+            ((Excluded, 13..15), "56"),
+        ];
+
+        let mut iter_actual = text
+            .match_indices(char::is_alphabetic)
+            .map(|(start, part)| {
+                let end = start + part.len() - 1;
+                start..=end
+            })
+            .every_range_inclusive(text.len())
+            .map(|(kind, range)| ((kind, range.clone()), &text[range]));
+
+        for expected in expected.iter().cloned() {
+            assert_eq!(Some(expected), iter_actual.next());
+        }
+
+        assert_eq!(None, iter_actual.next());
+    }
+
+    #[test]
+    fn every_range_inclusive_matches2() {
+        let text = "Foo12Bar34Baz56";
+
+        use EveryRangeKind::*;
+        let expected = [
+            ((Excluded, 0..3), "Foo"),
+            ((Included, 3..4), "1"),
+            ((Included, 4..5), "2"),
+            ((Excluded, 5..8), "Bar"),
+            ((Included, 8..9), "3"),
+            ((Included, 9..10), "4"),
+            ((Excluded, 10..13), "Baz"),
+            ((Included, 13..14), "5"),
+            ((Included, 14..15), "6"),
+        ];
+
+        let mut iter_actual = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| {
+                let end = start + part.len() - 1;
+                start..=end
+            })
+            .every_range_inclusive(text.len())
+            .map(|(kind, range)| ((kind, range.clone()), &text[range]));
+
+        for expected in expected.iter().cloned() {
+            assert_eq!(Some(expected), iter_actual.next());
+        }
+
+        assert_eq!(None, iter_actual.next());
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn every_range_inclusive_empty() {
+        use EveryRangeKind::*;
+
+        let items = [2..=1, 4..=5]
+            .iter()
+            .cloned()
+            .every_range_inclusive(6)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            items,
+            [
+                (Excluded, 0..2),
+                (Included, 2..2),
+                (Excluded, 2..4),
+                (Included, 4..6),
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic = "assertion failed: next.end <= self.end"]
+    fn range_end_after_end() {
+        [0..=1, 4..=5]
+            .iter()
+            .cloned()
+            .every_range_inclusive(5)
+            .for_each(|_| {});
+    }
+
+    #[test]
+    #[should_panic = "assertion failed: self.index <= next.start"]
+    fn range_start_after_index() {
+        [0..=3, 2..=5]
+            .iter()
+            .cloned()
+            .every_range_inclusive(6)
+            .for_each(|_| {});
+    }
+
+    #[test]
+    #[should_panic = "assertion failed: self.index <= next.start"]
+    fn ranges_out_of_order() {
+        [4..=5, 0..=1, 8..=9]
+            .iter()
+            .cloned()
+            .every_range_inclusive(20)
+            .for_each(|_| {});
+    }
+}
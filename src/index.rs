@@ -0,0 +1,138 @@
+use std::fmt;
+use std::ops::Range;
+
+use crate::EveryRangeIter;
+
+/// `RangeIndex` is implemented for the unsigned integer types, which
+/// can be used as the index type of an [`EveryRangeIter`].
+///
+/// This trait is sealed, and cannot be implemented outside of this crate.
+///
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub trait RangeIndex: sealed::Sealed + Copy + Ord + Default + fmt::Debug {}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_range_index {
+    ($($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+            impl RangeIndex for $t {}
+        )*
+    };
+}
+
+impl_range_index!(u8, u16, u32, u64, u128, usize);
+
+/// Trait which implements `every_range_generic` to get an `EveryRangeIter`
+/// over ranges of any [`RangeIndex`] type, e.g. `Range<u32>`.
+///
+/// [`EveryRange`] is only implemented for `Range<usize>`, such that
+/// ranges of integer literals, e.g. `vec![2..4].into_iter().every_range(6)`,
+/// continue to be inferred as `usize`.
+///
+/// *[See `EveryRangeIter` for more information.][`EveryRangeIter`]*
+///
+/// [`RangeIndex`]: trait.RangeIndex.html
+/// [`EveryRange`]: trait.EveryRange.html
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub trait EveryRangeGeneric<N>: Sized + Iterator<Item = Range<N>>
+where
+    N: RangeIndex,
+{
+    /// Create an [`EveryRangeIter`] with `end`, the same as [`every_range`],
+    /// but for any [`RangeIndex`] type.
+    ///
+    /// *Note that the adapters on [`EveryRangeIter`] are only
+    /// available for `usize`.*
+    ///
+    /// [`EveryRangeIter`]: struct.EveryRangeIter.html
+    /// [`every_range`]: trait.EveryRange.html#method.every_range
+    /// [`RangeIndex`]: trait.RangeIndex.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRangeGeneric, EveryRangeKind::*};
+    ///
+    /// let items = vec![2u32..4, 6..8]
+    ///     .into_iter()
+    ///     .every_range_generic(10)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     items,
+    ///     [
+    ///         (Excluded, 0..2),
+    ///         (Included, 2..4),
+    ///         (Excluded, 4..6),
+    ///         (Included, 6..8),
+    ///         (Excluded, 8..10),
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    fn every_range_generic(self, end: N) -> EveryRangeIter<Self, N> {
+        EveryRangeIter::with_index(self, N::default(), end)
+    }
+}
+
+impl<T, N> EveryRangeGeneric<N> for T
+where
+    T: Iterator<Item = Range<N>>,
+    N: RangeIndex,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn every_range_generic() {
+        let text = "Foo12Bar34Baz56";
+
+        let expected = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .collect::<Vec<_>>();
+
+        let actual = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| (start as u32)..((start + part.len()) as u32))
+            .every_range_generic(text.len() as u32)
+            .map(|(kind, range)| (kind, (range.start as usize)..(range.end as usize)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn every_range_generic_u8() {
+        let items = [0u8..0, 250..255]
+            .iter()
+            .cloned()
+            .every_range_generic(255)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            items,
+            [(Included, 0..0), (Excluded, 0..250), (Included, 250..255)]
+        );
+    }
+
+    #[test]
+    #[should_panic = "assertion failed: self.index <= next.start"]
+    fn every_range_generic_out_of_order() {
+        [4u64..6, 0..2]
+            .iter()
+            .cloned()
+            .every_range_generic(10)
+            .for_each(|_| {});
+    }
+}
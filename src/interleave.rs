@@ -0,0 +1,149 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{DebugPlaceholder, EveryRangeIter, EveryRangeKind};
+
+/// `InterleaveGapsIter` takes [`Excluded`] ranges from a caller
+/// supplied iterator, instead of generating them.
+///
+/// This `struct` is created by the [`interleave_gaps`]
+/// method on [`EveryRangeIter`].
+///
+/// [`interleave_gaps`]: struct.EveryRangeIter.html#method.interleave_gaps
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+pub struct InterleaveGapsIter<I, G>
+where
+    I: Iterator<Item = Range<usize>>,
+    G: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+    gap_producer: G,
+}
+
+impl<I, G> fmt::Debug for InterleaveGapsIter<I, G>
+where
+    I: Iterator<Item = Range<usize>>,
+    G: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InterleaveGapsIter")
+            .field("iter", &self.iter)
+            .field("gap_producer", &DebugPlaceholder("<iterator>"))
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create an [`InterleaveGapsIter`], which replaces each [`Excluded`]
+    /// range with the next range from `gap_producer`.
+    ///
+    /// If `gap_producer` is exhausted, or returns a range with a different
+    /// length than the generated [`Excluded`] range, then the generated
+    /// range is used instead. Either way, one range from `gap_producer`
+    /// is consumed per [`Excluded`] range.
+    ///
+    /// [`InterleaveGapsIter`]: struct.InterleaveGapsIter.html
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let gaps = vec![100..102, 200..201];
+    ///
+    /// let items = vec![2..4, 6..8]
+    ///     .into_iter()
+    ///     .every_range(10)
+    ///     .interleave_gaps(gaps.into_iter())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     items,
+    ///     [
+    ///         (Excluded, 100..102),
+    ///         (Included, 2..4),
+    ///         (Excluded, 4..6),
+    ///         (Included, 6..8),
+    ///         (Excluded, 8..10),
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    pub fn interleave_gaps<G>(self, gap_producer: G) -> InterleaveGapsIter<I, G>
+    where
+        G: Iterator<Item = Range<usize>>,
+    {
+        InterleaveGapsIter {
+            iter: self,
+            gap_producer,
+        }
+    }
+}
+
+impl<I, G> Iterator for InterleaveGapsIter<I, G>
+where
+    I: Iterator<Item = Range<usize>>,
+    G: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next()? {
+            (EveryRangeKind::Excluded, range) => match self.gap_producer.next() {
+                Some(gap) if gap.len() == range.len() => Some((EveryRangeKind::Excluded, gap)),
+                _ => Some((EveryRangeKind::Excluded, range)),
+            },
+            item => Some(item),
+        }
+    }
+}
+
+impl<I, G> FusedIterator for InterleaveGapsIter<I, G>
+where
+    I: Iterator<Item = Range<usize>>,
+    G: Iterator<Item = Range<usize>>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+
+    #[test]
+    fn interleave_gaps() {
+        let source = "Foo12Bar34Baz56###___";
+        let text = &source[..15];
+
+        let actual = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .interleave_gaps([15..18, 18..21].iter().cloned())
+            .map(|(_, range)| &source[range])
+            .collect::<String>();
+
+        assert_eq!(actual, "###12___34Baz56");
+    }
+
+    #[test]
+    fn interleave_gaps_exhausted() {
+        let ranges = [2..4, 6..8];
+
+        let actual = ranges
+            .iter()
+            .cloned()
+            .every_range(10)
+            .interleave_gaps(std::iter::empty())
+            .collect::<Vec<_>>();
+        let expected = ranges.iter().cloned().every_range(10).collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+}
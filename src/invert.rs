@@ -0,0 +1,95 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `InvertedIter` is an [`EveryRangeIter`], which swaps the
+/// [`Included`] and [`Excluded`] kinds of every item.
+///
+/// This `struct` is created by the [`every_range_inverted`]
+/// method on [`EveryRange`].
+///
+/// [`every_range_inverted`]: trait.EveryRange.html#method.every_range_inverted
+/// [`EveryRange`]: trait.EveryRange.html
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`Included`]: enum.EveryRangeKind.html#variant.Included
+/// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+pub struct InvertedIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+}
+
+impl<I> fmt::Debug for InvertedIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InvertedIter")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I> InvertedIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    #[inline]
+    pub(crate) fn new(ranges: I, end: usize) -> Self {
+        Self {
+            iter: EveryRangeIter::new(ranges, end),
+        }
+    }
+}
+
+impl<I> Iterator for InvertedIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(kind, range)| (kind.toggle(), range))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> FusedIterator for InvertedIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn every_range_inverted() {
+        let text = "Foo rust-lang.org Bar
+Baz crates.io Qux";
+
+        let actual = [4..17, 26..35]
+            .iter()
+            .cloned()
+            .every_range_inverted(text.len())
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Included, "Foo "),
+                (Excluded, "rust-lang.org"),
+                (Included, " Bar\nBaz "),
+                (Excluded, "crates.io"),
+                (Included, " Qux"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `RangeItem` is a named alternative to the
+/// `(EveryRangeKind, Range<usize>)` items of an [`EveryRangeIter`].
+///
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct RangeItem {
+    /// The kind of the range.
+    pub kind: EveryRangeKind,
+
+    /// The range.
+    pub range: Range<usize>,
+}
+
+impl RangeItem {
+    /// Returns `true` if the kind is [`Included`].
+    ///
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    #[inline]
+    pub fn is_included(&self) -> bool {
+        self.kind.is_included()
+    }
+
+    /// Returns the `(kind, range)` tuple.
+    #[inline]
+    pub fn into_inner(self) -> (EveryRangeKind, Range<usize>) {
+        (self.kind, self.range)
+    }
+}
+
+impl From<(EveryRangeKind, Range<usize>)> for RangeItem {
+    #[inline]
+    fn from((kind, range): (EveryRangeKind, Range<usize>)) -> Self {
+        Self { kind, range }
+    }
+}
+
+impl From<RangeItem> for (EveryRangeKind, Range<usize>) {
+    #[inline]
+    fn from(item: RangeItem) -> Self {
+        item.into_inner()
+    }
+}
+
+/// `ItemsEveryRangeIter` is an [`EveryRangeIter`], which yields
+/// [`RangeItem`]s instead of tuples.
+///
+/// This `struct` is created by the [`every_range_items`]
+/// method on [`EveryRange`].
+///
+/// [`every_range_items`]: trait.EveryRange.html#method.every_range_items
+/// [`EveryRange`]: trait.EveryRange.html
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`RangeItem`]: struct.RangeItem.html
+pub struct ItemsEveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+}
+
+impl<I> fmt::Debug for ItemsEveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ItemsEveryRangeIter")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I> ItemsEveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    #[inline]
+    pub(crate) fn new(ranges: I, end: usize) -> Self {
+        Self {
+            iter: EveryRangeIter::new(ranges, end),
+        }
+    }
+}
+
+impl<I> Iterator for ItemsEveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = RangeItem;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(RangeItem::from)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> FusedIterator for ItemsEveryRangeIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn every_range_items() {
+        let text = "Foo12Bar34Baz56";
+
+        let ranges = || {
+            text.match_indices(char::is_numeric)
+                .map(|(start, part)| start..(start + part.len()))
+        };
+
+        let mut digits = String::new();
+        for item in ranges().every_range_items(text.len()) {
+            match item {
+                RangeItem {
+                    kind: Included,
+                    range,
+                } => digits.push_str(&text[range]),
+                RangeItem { kind: Excluded, .. } => assert!(!item.is_included()),
+            }
+        }
+        assert_eq!(digits, "123456");
+
+        let tuples = ranges()
+            .every_range_items(text.len())
+            .map(<(EveryRangeKind, Range<usize>)>::from)
+            .collect::<Vec<_>>();
+        assert_eq!(tuples, ranges().every_range(text.len()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn range_item_from() {
+        let item = RangeItem::from((Included, 2..4));
+        assert!(item.is_included());
+        assert_eq!(
+            item,
+            RangeItem {
+                kind: Included,
+                range: 2..4,
+            }
+        );
+        assert_eq!(item.into_inner(), (Included, 2..4));
+
+        let (kind, range): (EveryRangeKind, Range<usize>) =
+            RangeItem::from((Excluded, 0..2)).into();
+        assert_eq!((kind, range), (Excluded, 0..2));
+    }
+}
@@ -0,0 +1,157 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use crate::{DebugPlaceholder, EveryRangeIter, EveryRangeKind};
+
+/// `LabeledIter` assigns a label to every [`Included`] range.
+///
+/// This `struct` is created by the [`label_included_with`]
+/// method on [`EveryRangeIter`].
+///
+/// [`label_included_with`]: struct.EveryRangeIter.html#method.label_included_with
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`Included`]: enum.EveryRangeKind.html#variant.Included
+pub struct LabeledIter<I, F, L>
+where
+    I: Iterator<Item = Range<usize>>,
+    F: FnMut(usize, &Range<usize>) -> L,
+{
+    iter: EveryRangeIter<I>,
+    f: F,
+    included_index: usize,
+    phantom: PhantomData<fn() -> L>,
+}
+
+impl<I, F, L> fmt::Debug for LabeledIter<I, F, L>
+where
+    I: Iterator<Item = Range<usize>>,
+    F: FnMut(usize, &Range<usize>) -> L,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LabeledIter")
+            .field("iter", &self.iter)
+            .field("f", &DebugPlaceholder("<closure>"))
+            .field("included_index", &self.included_index)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`LabeledIter`], which yields
+    /// `(EveryRangeKind, Range<usize>, Option<L>)`.
+    ///
+    /// For every [`Included`] range, `f` is called with the 0-based index
+    /// of the [`Included`] range along with the range itself, and the
+    /// returned label is yielded as `Some(label)`. [`Excluded`] ranges
+    /// are yielded with `None`.
+    ///
+    /// [`LabeledIter`]: struct.LabeledIter.html
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let kinds = ["URL", "EMAIL"];
+    ///
+    /// let labeled = vec![2..4, 6..8]
+    ///     .into_iter()
+    ///     .every_range(8)
+    ///     .label_included_with(|i, _| kinds[i])
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     labeled,
+    ///     [
+    ///         (Excluded, 0..2, None),
+    ///         (Included, 2..4, Some("URL")),
+    ///         (Excluded, 4..6, None),
+    ///         (Included, 6..8, Some("EMAIL")),
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    pub fn label_included_with<F, L>(self, f: F) -> LabeledIter<I, F, L>
+    where
+        F: FnMut(usize, &Range<usize>) -> L,
+    {
+        LabeledIter {
+            iter: self,
+            f,
+            included_index: 0,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I, F, L> Iterator for LabeledIter<I, F, L>
+where
+    I: Iterator<Item = Range<usize>>,
+    F: FnMut(usize, &Range<usize>) -> L,
+{
+    type Item = (EveryRangeKind, Range<usize>, Option<L>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (kind, range) = self.iter.next()?;
+
+        let label = match kind {
+            EveryRangeKind::Included => {
+                let label = (self.f)(self.included_index, &range);
+                self.included_index += 1;
+                Some(label)
+            }
+            EveryRangeKind::Excluded => None,
+        };
+
+        Some((kind, range, label))
+    }
+}
+
+impl<I, F, L> FusedIterator for LabeledIter<I, F, L>
+where
+    I: Iterator<Item = Range<usize>>,
+    F: FnMut(usize, &Range<usize>) -> L,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn label_included_with() {
+        let text = "Foo12Bar34Baz56";
+
+        let actual = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .label_included_with(|i, range| format!("{}:{}", i, &text[range.clone()]))
+            .collect::<Vec<_>>();
+
+        let label = |s: &str| Some(s.to_string());
+        assert_eq!(
+            actual,
+            [
+                (Excluded, 0..3, None),
+                (Included, 3..4, label("0:1")),
+                (Included, 4..5, label("1:2")),
+                (Excluded, 5..8, None),
+                (Included, 8..9, label("2:3")),
+                (Included, 9..10, label("3:4")),
+                (Excluded, 10..13, None),
+                (Included, 13..14, label("4:5")),
+                (Included, 14..15, label("5:6")),
+            ]
+        );
+    }
+}
@@ -62,13 +62,13 @@
 //! # Example: "Autolink" or HTMLify URLs
 //!
 //! Using [`every_range`] it is easy to collect ranges or
-//! substring into a [`String`].
+//! substring into a [`String`], e.g. with [`collect_str_replace_included`].
 //!
 //! [`String`]: https://doc.rust-lang.org/stable/std/string/struct.String.html
+//! [`collect_str_replace_included`]: struct.EveryRangeIter.html#method.collect_str_replace_included
 //!
 //! ```no_run
-//! use std::borrow::Cow;
-//! use every_range::{EveryRange, EveryRangeKind};
+//! use every_range::EveryRange;
 //!
 //! let text = "Foo rust-lang.org Bar
 //! Baz crates.io Qux";
@@ -82,16 +82,9 @@
 //! let output = ranges
 //!     .into_iter()
 //!     .every_range(text.len())
-//!     .map(|(kind, range)| {
-//!         if kind == EveryRangeKind::Included {
-//!             let url = &text[range];
-//!             format!("<a href=\"{0}\">{0}</a>", url).into()
-//!         } else {
-//!             Cow::Borrowed(&text[range])
-//!         }
-//!     })
-//!     .collect::<Vec<_>>()
-//!     .concat();
+//!     .collect_str_replace_included(text, |url| {
+//!         format!("<a href=\"{0}\">{0}</a>", url).into()
+//!     });
 //!
 //! println!("{}", output);
 //! ```
@@ -103,160 +96,1621 @@
 //! Baz <a href="crates.io">crates.io</a> Qux
 //! ```
 
-#![forbid(unsafe_code)]
-#![deny(missing_docs)]
-#![deny(missing_debug_implementations)]
-#![warn(clippy::all)]
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+#![deny(missing_debug_implementations)]
+#![warn(clippy::all)]
+
+use std::error;
+use std::fmt;
+use std::iter::{self, FusedIterator};
+use std::ops::{Not, Range};
+use std::option;
+use std::str::FromStr;
+
+mod builder;
+mod cache;
+mod cancel;
+mod chars;
+mod chunk;
+mod clamp;
+mod coalesce;
+mod concat;
+mod consecutive;
+mod contiguous;
+mod coverage;
+mod cross;
+mod dedup;
+mod diff;
+mod event;
+mod fallible;
+mod fill;
+mod filter;
+mod hash;
+mod inclusive;
+mod index;
+mod interleave;
+mod invert;
+mod item;
+mod label;
+mod limit;
+mod lines;
+mod map;
+mod merge;
+mod offsets;
+mod overlap;
+mod pattern;
+mod peek;
+mod ratio;
+mod reorder;
+mod retain;
+mod rev;
+mod sentinel;
+mod skip;
+mod skipped;
+mod sort;
+mod sparse;
+mod split;
+mod stats;
+mod stride;
+mod tagged;
+mod take;
+#[cfg(feature = "testing")]
+mod throttle;
+#[cfg(feature = "timing")]
+mod timing;
+mod utf8;
+mod window;
+mod words;
+mod write;
+mod zip;
+
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
+
+pub use builder::{every_range_builder, BuiltRanges, EveryRangeBuilder};
+pub use cache::{CachedFirstN, CachingIter};
+pub use cancel::{CancellableIter, CancellationToken};
+pub use chars::CharOffsetIndexedIter;
+pub use chunk::SplitEveryNBytesIter;
+pub use clamp::ClampedEveryRangeIter;
+pub use consecutive::MergeConsecutiveIter;
+pub use contiguous::AssertNoOverlapIter;
+pub use coverage::CoverageVec;
+pub use cross::{AgreementKind, CrossProductIter};
+pub use dedup::{DedupByRangeIter, DeduplicateByIter};
+pub use diff::TextDiff;
+pub use event::{EventHandler, EventStream};
+pub use fallible::{
+    validate_ranges, CheckedBoundsIter, EveryRangeError, TryEveryRange, TryEveryRangeIter,
+};
+pub use fill::FillGapsIter;
+pub use filter::{FilterKindIter, KindFilterIter, NonEmptyIter};
+pub use hash::ChecksummedIter;
+pub use inclusive::{EveryRangeInclusive, EveryRangeInclusiveIter, InclusiveRanges};
+pub use index::{EveryRangeGeneric, RangeIndex};
+pub use interleave::InterleaveGapsIter;
+pub use invert::InvertedIter;
+pub use item::{ItemsEveryRangeIter, RangeItem};
+pub use label::LabeledIter;
+pub use limit::{AtMostNOfEachIter, CountDownIter};
+pub use lines::LineRangesIter;
+pub use map::{FallibleRangeMapIter, IntoRangesIter, MapByKindIter};
+pub use merge::{covers_entirely, merge_adjacent, merge_overlapping, total_covered_len};
+pub use offsets::{OffsetAnnotatedIter, SeqIndexedIter};
+pub use overlap::OverlapGroupIter;
+pub use pattern::{CharRunRanges, StrMatchRanges};
+pub use ratio::RatioIter;
+pub use reorder::ReorderedIter;
+pub use retain::RetainKindIter;
+pub use rev::RevEveryRangeIter;
+pub use sentinel::WithEndSentinelIter;
+pub use skip::SkipFirstNExcludedIter;
+pub use skipped::{SkipReport, SkipReportingIter};
+pub use sort::{sort_and_dedup_ranges, sort_ranges, BufferedSortRanges, BufferedSortedIter};
+pub use sparse::SparseRegionIter;
+pub use split::SplitOnLongGapIter;
+pub use stats::{CoverageReport, EveryRangeExt, EveryRangeStats, SpanMeasurements};
+pub use stride::StrideIncludedIter;
+pub use tagged::{EveryRangeTagged, TaggedEveryRangeIter};
+pub use take::TakeWhileShortGapIter;
+#[cfg(feature = "testing")]
+pub use throttle::ThrottledIter;
+#[cfg(feature = "timing")]
+pub use timing::TimedIter;
+pub use utf8::{CharAwareIter, FlatSingleCharIter, Utf8BoundaryError};
+pub use window::WindowedEveryRangeIter;
+pub use words::{WordBoundaryIter, WordContractIter};
+pub use zip::ZipStrIter;
+
+/// `EveryRangeKind` can be used to distinguish original input
+/// ranges from generates ranges.
+///
+/// `EveryRangeKind` is ordered with `Included < Excluded`.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub enum EveryRangeKind {
+    /// `Included` ranges are the ones produces by the inner [`Iterator`].
+    ///
+    /// [`Iterator`]: https://doc.rust-lang.org/stable/std/iter/trait.Iterator.html
+    Included,
+
+    /// Excluded ranges are the ones generated dynamically by [`EveryRangeIter`].
+    ///
+    /// [`EveryRangeIter`]: struct.EveryRangeIter.html
+    Excluded,
+}
+
+impl EveryRangeKind {
+    /// Returns `true` if the kind is [`Included`].
+    ///
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    #[inline]
+    pub fn is_included(self) -> bool {
+        self == EveryRangeKind::Included
+    }
+
+    /// Returns `true` if the kind is [`Excluded`].
+    ///
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    #[inline]
+    pub fn is_excluded(self) -> bool {
+        self == EveryRangeKind::Excluded
+    }
+
+    /// Returns `"included"` or `"excluded"`.
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EveryRangeKind::Included => "included",
+            EveryRangeKind::Excluded => "excluded",
+        }
+    }
+
+    /// Returns the other kind, i.e. [`Included`] becomes [`Excluded`]
+    /// and vice versa.
+    ///
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let kinds = vec![2..4]
+    ///     .into_iter()
+    ///     .every_range(6)
+    ///     .map(|(kind, _)| kind.toggle())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(kinds, [Included, Excluded, Included]);
+    /// ```
+    #[inline]
+    pub fn toggle(self) -> Self {
+        match self {
+            EveryRangeKind::Included => EveryRangeKind::Excluded,
+            EveryRangeKind::Excluded => EveryRangeKind::Included,
+        }
+    }
+}
+
+impl Not for EveryRangeKind {
+    type Output = Self;
+
+    /// Returns the other kind, same as [`toggle`].
+    ///
+    /// [`toggle`]: enum.EveryRangeKind.html#method.toggle
+    #[inline]
+    fn not(self) -> Self::Output {
+        self.toggle()
+    }
+}
+
+impl Default for EveryRangeKind {
+    /// Returns [`Excluded`], i.e. the kind of the gaps,
+    /// which is the "background" state.
+    ///
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    #[inline]
+    fn default() -> Self {
+        EveryRangeKind::Excluded
+    }
+}
+
+impl From<bool> for EveryRangeKind {
+    /// Converts `true` into [`Included`] and `false` into [`Excluded`].
+    ///
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    #[inline]
+    fn from(included: bool) -> Self {
+        if included {
+            EveryRangeKind::Included
+        } else {
+            EveryRangeKind::Excluded
+        }
+    }
+}
+
+impl From<EveryRangeKind> for bool {
+    /// Converts [`Included`] into `true` and [`Excluded`] into `false`.
+    ///
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    #[inline]
+    fn from(kind: EveryRangeKind) -> Self {
+        kind.is_included()
+    }
+}
+
+impl fmt::Display for EveryRangeKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for EveryRangeKind {
+    type Err = ParseEveryRangeKindError;
+
+    /// Parse `"included"` or `"excluded"` case-insensitively.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::EveryRangeKind::{self, *};
+    ///
+    /// assert_eq!("included".parse::<EveryRangeKind>(), Ok(Included));
+    /// assert_eq!("Excluded".parse::<EveryRangeKind>(), Ok(Excluded));
+    /// assert!("foo".parse::<EveryRangeKind>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("included") {
+            Ok(EveryRangeKind::Included)
+        } else if s.eq_ignore_ascii_case("excluded") {
+            Ok(EveryRangeKind::Excluded)
+        } else {
+            Err(ParseEveryRangeKindError(()))
+        }
+    }
+}
+
+/// `ParseEveryRangeKindError` is returned when parsing an
+/// [`EveryRangeKind`] from a string fails.
+///
+/// [`EveryRangeKind`]: enum.EveryRangeKind.html
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ParseEveryRangeKindError(());
+
+impl fmt::Display for ParseEveryRangeKindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected \"included\" or \"excluded\"")
+    }
+}
+
+impl error::Error for ParseEveryRangeKindError {}
+
+// TODO: Add an `async` feature with `EveryRangeIter::into_stream`, implementing `futures_core::Stream` by driving the iterator synchronously in `poll_next` (blocked on adding the `futures-core` dependency)
+// TODO: Add a `ropey` feature with `EveryRangeIter::collect_to_rope(text, included_fn)`, building a `ropey::Rope` from the excluded segments and the transformed included segments (blocked on adding the `ropey` dependency)
+// TODO: Add a `bitvec` feature with `EveryRangeIter::into_binary_mask`, like `into_coverage_vec` but packed into a `bitvec::vec::BitVec` (blocked on adding the `bitvec` dependency)
+// TODO: Along with `into_binary_mask`, add `EveryRangeIter::from_binary_mask(mask, end)`, run-length decoding the bits into ranges and validating `mask.len() == end` (blocked on adding the `bitvec` dependency)
+// TODO: Add a `rayon` feature with `EveryRangeIter::collect_parallel`, eagerly collecting into an `EveryRangeCollection` implementing `rayon::iter::IntoParallelIterator` (blocked on adding the `rayon` dependency)
+// TODO: Add a `serde` feature deriving `Serialize` and `Deserialize` for `EveryRangeKind` with `#[serde(rename_all = "lowercase")]`, matching `Display` and `FromStr` (blocked on adding the `serde` dependency)
+
+/// `EveryRangeIter` iterates over [`Range`]s and "fill in"
+/// missing ranges, i.e. the gap between two consecutive ranges.
+/// The original ranges and the generated ones,
+/// can be distinguished by the [`Included`] and
+/// [`Excluded`] enum variants.
+///
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`Included`]: enum.EveryRangeKind.html#variant.Included
+/// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+///
+/// [`Range`]: https://doc.rust-lang.org/stable/std/ops/struct.Range.html
+///
+/// # Panics
+///
+/// `EveryRangeIter` panics in the following conditions.
+///
+/// - Panics if [`Range`]s are received out of order.
+/// - Panics if [`Range`]s overlap.
+/// - Panics if any [`Range`] exceeds the `end` of the `EveryRangeIter`.
+///
+/// For untrusted input, use [`try_every_range`] to return an error instead,
+/// [`every_range_clamped`] to clamp or skip the invalid ranges, or
+/// [`EveryRangeBuilder::lenient`].
+///
+/// [`try_every_range`]: trait.TryEveryRange.html#method.try_every_range
+/// [`every_range_clamped`]: trait.EveryRange.html#method.every_range_clamped
+/// [`EveryRangeBuilder::lenient`]: struct.EveryRangeBuilder.html#method.lenient
+pub struct EveryRangeIter<I, N = usize>
+where
+    I: Iterator<Item = Range<N>>,
+    N: RangeIndex,
+{
+    index: N,
+    end: N,
+    iter: I,
+    next: Option<Range<N>>,
+    /// The index used when iterating from the back, see `next_back`.
+    back_index: N,
+    back: Option<Range<N>>,
+    /// Whether to suppress the trailing `Excluded` range, see `skip_trailing`.
+    skip_trailing: bool,
+}
+
+/// Printed in place of a field which might not implement `Debug`,
+/// e.g. the inner iterator or a closure.
+pub(crate) struct DebugPlaceholder(pub(crate) &'static str);
+
+impl fmt::Debug for DebugPlaceholder {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl<I, N> fmt::Debug for EveryRangeIter<I, N>
+where
+    I: Iterator<Item = Range<N>>,
+    N: RangeIndex,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EveryRangeIter")
+            .field("index", &self.index)
+            .field("end", &self.end)
+            .field("iter", &DebugPlaceholder("<iterator>"))
+            .field("next", &self.next)
+            .field("back_index", &self.back_index)
+            .field("back", &self.back)
+            .field("skip_trailing", &self.skip_trailing)
+            .finish()
+    }
+}
+
+impl<I, N> Clone for EveryRangeIter<I, N>
+where
+    I: Clone + Iterator<Item = Range<N>>,
+    N: RangeIndex,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            index: self.index,
+            end: self.end,
+            iter: self.iter.clone(),
+            next: self.next.clone(),
+            back_index: self.back_index,
+            back: self.back.clone(),
+            skip_trailing: self.skip_trailing,
+        }
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create an `EveryRangeIter` with an `iter` and `end`,
+    /// which represents the "end point". Thereby, if `end` is
+    /// greater than the last [`range.end`] then an ending
+    /// [`Excluded`] range is generated, otherwise no additional
+    /// ending range is generated.
+    ///
+    /// *See [`every_range_generic`] for index types other than `usize`.*
+    ///
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    /// [`every_range_generic`]: trait.EveryRangeGeneric.html#method.every_range_generic
+    ///
+    /// [`range.end`]: https://doc.rust-lang.org/stable/std/ops/struct.Range.html#structfield.end
+    #[inline]
+    pub fn new(iter: I, end: usize) -> Self {
+        Self::with_index(iter, 0, end)
+    }
+
+    /// Suppress the next item, if it is an [`Excluded`] range, e.g.
+    /// the leading `(Excluded, 0..range.start)` when the first range
+    /// does not start at `0`.
+    ///
+    /// *Note that this pulls the next range from the inner iterator
+    /// immediately.*
+    ///
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![2..4]
+    ///     .into_iter()
+    ///     .every_range(6)
+    ///     .skip_leading()
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Included, 2..4), (Excluded, 4..6)]);
+    /// ```
+    pub fn skip_leading(mut self) -> Self {
+        let next = self
+            .next
+            .take()
+            .or_else(|| self.iter.next())
+            .or_else(|| self.back.take());
+
+        if let Some(next) = next {
+            // Invalid ranges are left for `next` to panic on
+            self.index = self.index.max(next.start);
+            self.next = Some(next);
+        } else {
+            self.index = self.index.max(self.back_index);
+        }
+
+        self
+    }
+
+    /// Suppress the trailing [`Excluded`] range, i.e. the
+    /// `(Excluded, range.end..end)` when the last range
+    /// does not end at `end`.
+    ///
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![2..4]
+    ///     .into_iter()
+    ///     .every_range(6)
+    ///     .skip_leading()
+    ///     .skip_trailing()
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Included, 2..4)]);
+    /// ```
+    #[inline]
+    pub fn skip_trailing(mut self) -> Self {
+        self.skip_trailing = true;
+        self
+    }
+}
+
+impl EveryRangeIter<iter::Once<Range<usize>>> {
+    /// Create an `EveryRangeIter` over a single `range`, i.e. yielding
+    /// at most three items, the part before `range`, `range` itself, and
+    /// the part after `range`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRangeIter, EveryRangeKind::*};
+    ///
+    /// let items = EveryRangeIter::every_range_single(2..4, 6).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Excluded, 0..2), (Included, 2..4), (Excluded, 4..6)]);
+    /// ```
+    #[inline]
+    pub fn every_range_single(range: Range<usize>, end: usize) -> Self {
+        Self::new(iter::once(range), end)
+    }
+}
+
+impl EveryRangeIter<iter::Empty<Range<usize>>> {
+    /// Create an `EveryRangeIter` without any ranges, i.e. yielding
+    /// a single [`Excluded`] range covering `0..end`, if `end` is
+    /// greater than `0`.
+    ///
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRangeIter, EveryRangeKind::*};
+    ///
+    /// let items = EveryRangeIter::every_range_empty(6).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Excluded, 0..6)]);
+    /// ```
+    #[inline]
+    pub fn every_range_empty(end: usize) -> Self {
+        Self::new(iter::empty(), end)
+    }
+}
+
+impl<I, N> EveryRangeIter<I, N>
+where
+    I: Iterator<Item = Range<N>>,
+    N: RangeIndex,
+{
+    /// Create an `EveryRangeIter` which starts at `index` instead of `0`.
+    #[inline]
+    pub(crate) fn with_index(iter: I, index: N, end: N) -> Self {
+        Self {
+            index,
+            end,
+            iter,
+            next: None,
+            back_index: end,
+            back: None,
+            skip_trailing: false,
+        }
+    }
+
+    /// Split the `EveryRangeIter` into its remaining input ranges,
+    /// i.e. including the pending ranges of both ends, and the `Rebuild`
+    /// state needed to create an `EveryRangeIter` in place of it.
+    ///
+    /// Adapters which rebuild the `EveryRangeIter` around other ranges
+    /// must go through this, such that e.g. `skip_trailing` is kept, and
+    /// items already consumed from the back are not yielded again.
+    #[inline]
+    pub(crate) fn into_rebuild(self) -> (RemainingRanges<I, N>, Rebuild<N>) {
+        let rebuild = Rebuild {
+            index: self.index,
+            end: self.back_index,
+            skip_trailing: self.skip_trailing && (self.back_index == self.end),
+        };
+
+        let ranges = self.next.into_iter().chain(self.iter).chain(self.back);
+
+        (ranges, rebuild)
+    }
+
+    /// Create an `EveryRangeIter` in place of `self`, over the
+    /// ranges returned by `f` given the remaining input ranges,
+    /// see `into_rebuild`.
+    #[inline]
+    pub(crate) fn rebuild<J, F>(self, f: F) -> EveryRangeIter<J, N>
+    where
+        J: Iterator<Item = Range<N>>,
+        F: FnOnce(RemainingRanges<I, N>) -> J,
+    {
+        let (ranges, rebuild) = self.into_rebuild();
+        rebuild.build(f(ranges), rebuild.index, rebuild.end)
+    }
+
+    /// Returns the current index, i.e. the start of the next item.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::EveryRange;
+    ///
+    /// let mut iter = vec![2..4].into_iter().every_range(6);
+    /// assert_eq!(iter.current_index(), 0);
+    ///
+    /// iter.next();
+    /// assert_eq!(iter.current_index(), 2);
+    /// ```
+    #[inline]
+    pub fn current_index(&self) -> N {
+        self.index
+    }
+
+    /// Returns the `end` of the `EveryRangeIter`.
+    #[inline]
+    pub fn end(&self) -> N {
+        self.end
+    }
+
+    /// Returns the range not yet covered by the yielded items,
+    /// i.e. `current_index()..end()`, unless items have been
+    /// yielded from the back.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::EveryRange;
+    ///
+    /// let mut iter = vec![2..4].into_iter().every_range(6);
+    /// assert_eq!(iter.remaining_range(), 0..6);
+    ///
+    /// iter.next();
+    /// assert_eq!(iter.remaining_range(), 2..6);
+    ///
+    /// iter.next_back();
+    /// assert_eq!(iter.remaining_range(), 2..4);
+    /// ```
+    #[inline]
+    pub fn remaining_range(&self) -> Range<N> {
+        self.index..self.back_index
+    }
+}
+
+/// The remaining input ranges of an `EveryRangeIter`, see `into_rebuild`.
+pub(crate) type RemainingRanges<I, N = usize> =
+    iter::Chain<iter::Chain<option::IntoIter<Range<N>>, I>, option::IntoIter<Range<N>>>;
+
+/// The state of an `EveryRangeIter`, which is carried over by
+/// adapters that rebuild it around other ranges, see `into_rebuild`.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Rebuild<N> {
+    /// The index of the first remaining item.
+    pub(crate) index: N,
+    /// The end of the remaining items, i.e. excluding
+    /// the items already consumed from the back.
+    pub(crate) end: N,
+    skip_trailing: bool,
+}
+
+impl<N> Rebuild<N>
+where
+    N: RangeIndex,
+{
+    /// Create an `EveryRangeIter` over `iter` covering `index..end`, which
+    /// suppresses the trailing `Excluded` range, if `end` is the end of the
+    /// original `EveryRangeIter` and it suppressed it.
+    #[inline]
+    pub(crate) fn build<J>(self, iter: J, index: N, end: N) -> EveryRangeIter<J, N>
+    where
+        J: Iterator<Item = Range<N>>,
+    {
+        debug_assert!(end <= self.end);
+
+        let mut iter = EveryRangeIter::with_index(iter, index, end);
+        iter.skip_trailing = self.skip_trailing && (end == self.end);
+        iter
+    }
+}
+
+impl<I, N> Iterator for EveryRangeIter<I, N>
+where
+    I: Iterator<Item = Range<N>>,
+    N: RangeIndex,
+{
+    type Item = (EveryRangeKind, Range<N>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self
+            .next
+            .take()
+            .or_else(|| self.iter.next())
+            .or_else(|| self.back.take());
+
+        if let Some(next) = next {
+            assert!(self.index <= next.start);
+            assert!(next.end <= self.end);
+            assert!(next.start <= next.end);
+
+            if self.index < next.start {
+                let start = self.index;
+                self.index = next.start;
+                self.next = Some(next);
+
+                Some((EveryRangeKind::Excluded, start..self.index))
+            } else {
+                self.index = next.end;
+
+                Some((EveryRangeKind::Included, next))
+            }
+        } else if self.index < self.back_index {
+            let start = self.index;
+
+            let trailing = self.back_index == self.end;
+            self.index = self.back_index;
+
+            if self.skip_trailing && trailing {
+                return None;
+            }
+
+            Some((EveryRangeKind::Excluded, start..self.index))
+        } else {
+            None
+        }
+    }
+
+    /// Skips whole ranges at a time, without generating the skipped items.
+    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
+        while n > 0 {
+            let next = self
+                .next
+                .take()
+                .or_else(|| self.iter.next())
+                .or_else(|| self.back.take());
+
+            let next = match next {
+                Some(next) => next,
+                None => {
+                    // Only the trailing `Excluded` range can remain
+                    self.index = self.index.max(self.back_index);
+                    return None;
+                }
+            };
+
+            assert!(self.index <= next.start);
+            assert!(next.end <= self.end);
+            assert!(next.start <= next.end);
+
+            if self.index < next.start {
+                if n == 1 {
+                    // Skip only the `Excluded` range before `next`
+                    self.index = next.start;
+                    self.next = Some(next);
+                    break;
+                }
+
+                n -= 2;
+            } else {
+                n -= 1;
+            }
+
+            self.index = next.end;
+        }
+
+        self.next()
+    }
+
+    /// Iterates the remaining ranges without the
+    /// bookkeeping of the pending range in `next`.
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let mut acc = init;
+
+        let back = self.back.take();
+        for next in self
+            .next
+            .take()
+            .into_iter()
+            .chain(&mut self.iter)
+            .chain(back)
+        {
+            assert!(self.index <= next.start);
+            assert!(next.end <= self.end);
+            assert!(next.start <= next.end);
+
+            if self.index < next.start {
+                acc = f(acc, (EveryRangeKind::Excluded, self.index..next.start));
+            }
+
+            self.index = next.end;
+
+            acc = f(acc, (EveryRangeKind::Included, next));
+        }
+
+        let trailing = self.back_index == self.end;
+        if (self.index < self.back_index) && !(self.skip_trailing && trailing) {
+            acc = f(acc, (EveryRangeKind::Excluded, self.index..self.back_index));
+        }
+
+        acc
+    }
+
+    /// Every remaining range yields at most two items, i.e. itself
+    /// and the gap before it, along with at most one trailing gap.
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = (self.next.is_some() as usize) + (self.back.is_some() as usize);
+        let (lower, upper) = self.iter.size_hint();
+
+        // The trailing gap is only yielded if it is not suppressed
+        let gap = !(self.skip_trailing && (self.back_index == self.end)) as usize;
+
+        let lower = lower
+            .saturating_add(pending)
+            .max(((self.index < self.back_index) as usize) * gap);
+        let upper = upper
+            .and_then(|upper| upper.checked_add(pending))
+            .and_then(|upper| upper.checked_mul(2))
+            .and_then(|upper| upper.checked_add(gap));
+
+        (lower, upper)
+    }
+}
+
+/// When the inner iterator is double-ended, then `EveryRangeIter` can also
+/// be iterated from the back, yielding the same items in reverse order.
+///
+/// Iterating from both ends can be mixed, the same as any other
+/// [`DoubleEndedIterator`]. The same panic conditions apply, i.e. when
+/// iterating from the back, ranges must be received in reverse order.
+///
+/// [`DoubleEndedIterator`]: https://doc.rust-lang.org/stable/std/iter/trait.DoubleEndedIterator.html
+///
+/// # Example
+///
+/// ```
+/// use every_range::{EveryRange, EveryRangeKind::*};
+///
+/// let items = vec![2..4, 6..8]
+///     .into_iter()
+///     .every_range(10)
+///     .rev()
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(
+///     items,
+///     [
+///         (Excluded, 8..10),
+///         (Included, 6..8),
+///         (Excluded, 4..6),
+///         (Included, 2..4),
+///         (Excluded, 0..2),
+///     ]
+/// );
+/// ```
+impl<I, N> DoubleEndedIterator for EveryRangeIter<I, N>
+where
+    I: DoubleEndedIterator<Item = Range<N>>,
+    N: RangeIndex,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let next = self
+            .back
+            .take()
+            .or_else(|| self.iter.next_back())
+            .or_else(|| self.next.take());
+
+        if let Some(next) = next {
+            assert!(next.end <= self.end);
+            assert!(next.end <= self.back_index);
+            assert!(next.start <= next.end);
+            assert!(self.index <= next.start);
+
+            if self.skip_trailing && (self.back_index == self.end) {
+                self.back_index = next.start;
+
+                Some((EveryRangeKind::Included, next))
+            } else if next.end < self.back_index {
+                let end = self.back_index;
+                self.back_index = next.end;
+                self.back = Some(next);
+
+                Some((EveryRangeKind::Excluded, self.back_index..end))
+            } else {
+                self.back_index = next.start;
+
+                Some((EveryRangeKind::Included, next))
+            }
+        } else if self.index < self.back_index {
+            let end = self.back_index;
+
+            let trailing = self.back_index == self.end;
+            self.back_index = self.index;
+
+            if self.skip_trailing && trailing {
+                return None;
+            }
+
+            Some((EveryRangeKind::Excluded, self.index..end))
+        } else {
+            None
+        }
+    }
+}
+
+impl<I, N> FusedIterator for EveryRangeIter<I, N>
+where
+    I: Iterator<Item = Range<N>>,
+    N: RangeIndex,
+{
+}
+
+/// Trait which implements `every_range` to get a `EveryRangeIter`.
+///
+/// *[See `EveryRangeIter` for more information.][`EveryRangeIter`]*
+///
+/// [`every_range`]: trait.EveryRange.html#method.every_range
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub trait EveryRange: Sized + Iterator<Item = Range<usize>> {
+    /// Create an [`EveryRangeIter`] with `end`, which represents
+    /// the "end point". Thereby, if `end` is greater than the last
+    /// [`range.end`] then an ending [`Excluded`] range is generated,
+    /// otherwise no additional ending range is generated.
+    ///
+    /// *[See `EveryRangeIter` for more information.][`EveryRangeIter`]*
+    ///
+    /// [`EveryRangeIter`]: struct.EveryRangeIter.html
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// [`range.end`]: https://doc.rust-lang.org/stable/std/ops/struct.Range.html#structfield.end
+    #[inline]
+    fn every_range(self, end: usize) -> EveryRangeIter<Self> {
+        EveryRangeIter::new(self, end)
+    }
+
+    /// Create an [`EveryRangeIter`] like [`every_range`], but which starts
+    /// at `start` instead of `0`. Thereby, if the first range starts
+    /// after `start`, then a leading [`Excluded`] range `start..range.start`
+    /// is generated.
+    ///
+    /// This is useful when the ranges are relative to a larger text,
+    /// but only a substring of it is being processed.
+    ///
+    /// [`EveryRangeIter`]: struct.EveryRangeIter.html
+    /// [`every_range`]: trait.EveryRange.html#method.every_range
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` is greater than `end`. Additionally, the iterator
+    /// panics if any range starts before `start`, the same as it does
+    /// for ranges received out of order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![4..6].into_iter().every_range_from(2, 8).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Excluded, 2..4), (Included, 4..6), (Excluded, 6..8)]);
+    /// ```
+    #[inline]
+    fn every_range_from(self, start: usize, end: usize) -> EveryRangeIter<Self> {
+        assert!(start <= end);
+        EveryRangeIter::with_index(self, start, end)
+    }
+
+    /// Create a [`ClampedEveryRangeIter`], which is a lenient version of
+    /// [`every_range`], that never panics due to invalid ranges.
+    ///
+    /// - Ranges exceeding `end` are clamped to `end`.
+    /// - Ranges starting before the end of the previously accepted range,
+    ///   i.e. overlapping or out of order ranges, are skipped.
+    /// - Ranges starting after `end` or after their own end are skipped.
+    ///
+    /// Gaps between the accepted ranges are filled in as usual.
+    ///
+    /// *See also [`report_skipped`], which reports the skipped ranges.*
+    ///
+    /// [`ClampedEveryRangeIter`]: struct.ClampedEveryRangeIter.html
+    /// [`every_range`]: trait.EveryRange.html#method.every_range
+    /// [`report_skipped`]: struct.EveryRangeIter.html#method.report_skipped
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![2..4, 3..5, 6..12]
+    ///     .into_iter()
+    ///     .every_range_clamped(8)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Excluded, 0..2), (Included, 2..4), (Excluded, 4..6), (Included, 6..8)]);
+    /// ```
+    #[inline]
+    fn every_range_clamped(self, end: usize) -> ClampedEveryRangeIter<Self> {
+        ClampedEveryRangeIter::new(self, end)
+    }
+
+    /// Create an [`EveryRangeIter`] with `end`, the same as [`every_range`],
+    /// but without the leading [`Excluded`] range, if the first range
+    /// does not start at `0`.
+    ///
+    /// *See [`skip_leading`] for more information.*
+    ///
+    /// [`EveryRangeIter`]: struct.EveryRangeIter.html
+    /// [`every_range`]: trait.EveryRange.html#method.every_range
+    /// [`skip_leading`]: struct.EveryRangeIter.html#method.skip_leading
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![5..10].into_iter().every_range_no_leading(15).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Included, 5..10), (Excluded, 10..15)]);
+    /// ```
+    #[inline]
+    fn every_range_no_leading(self, end: usize) -> EveryRangeIter<Self> {
+        self.every_range(end).skip_leading()
+    }
+
+    /// Create an [`EveryRangeIter`] with `end`, the same as [`every_range`],
+    /// but without the trailing [`Excluded`] range, if the last range
+    /// does not end at `end`.
+    ///
+    /// *See [`skip_trailing`] for more information.*
+    ///
+    /// [`EveryRangeIter`]: struct.EveryRangeIter.html
+    /// [`every_range`]: trait.EveryRange.html#method.every_range
+    /// [`skip_trailing`]: struct.EveryRangeIter.html#method.skip_trailing
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![2..5].into_iter().every_range_no_trailing(10).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Excluded, 0..2), (Included, 2..5)]);
+    /// ```
+    #[inline]
+    fn every_range_no_trailing(self, end: usize) -> EveryRangeIter<Self> {
+        self.every_range(end).skip_trailing()
+    }
+
+    /// Create an [`InvertedIter`] with `end`, the same as [`every_range`],
+    /// but where [`Included`] and [`Excluded`] are swapped, i.e. where the
+    /// gaps between the ranges are [`Included`].
+    ///
+    /// [`InvertedIter`]: struct.InvertedIter.html
+    /// [`every_range`]: trait.EveryRange.html#method.every_range
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![2..4].into_iter().every_range_inverted(6).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Included, 0..2), (Excluded, 2..4), (Included, 4..6)]);
+    /// ```
+    #[inline]
+    fn every_range_inverted(self, end: usize) -> InvertedIter<Self> {
+        InvertedIter::new(self, end)
+    }
+
+    /// Create a [`NonEmptyIter`] with `end`, the same as [`every_range`],
+    /// but which skips all zero-length ranges.
+    ///
+    /// The generated [`Excluded`] ranges are never zero-length, e.g.
+    /// between adjacent ranges. Thereby, only zero-length input ranges,
+    /// e.g. `4..4`, are skipped.
+    ///
+    /// [`NonEmptyIter`]: struct.NonEmptyIter.html
+    /// [`every_range`]: trait.EveryRange.html#method.every_range
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![2..4, 4..4, 4..6]
+    ///     .into_iter()
+    ///     .every_range_non_empty(6)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Excluded, 0..2), (Included, 2..4), (Included, 4..6)]);
+    /// ```
+    #[inline]
+    fn every_range_non_empty(self, end: usize) -> NonEmptyIter<Self> {
+        NonEmptyIter::new(self, end)
+    }
+
+    /// Create an [`ItemsEveryRangeIter`] with `end`, the same as
+    /// [`every_range`], but which yields [`RangeItem`]s instead of tuples.
+    ///
+    /// [`ItemsEveryRangeIter`]: struct.ItemsEveryRangeIter.html
+    /// [`every_range`]: trait.EveryRange.html#method.every_range
+    /// [`RangeItem`]: struct.RangeItem.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*, RangeItem};
+    ///
+    /// let items = vec![2..4].into_iter().every_range_items(4).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     items,
+    ///     [
+    ///         RangeItem { kind: Excluded, range: 0..2 },
+    ///         RangeItem { kind: Included, range: 2..4 },
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    fn every_range_items(self, end: usize) -> ItemsEveryRangeIter<Self> {
+        ItemsEveryRangeIter::new(self, end)
+    }
+
+    /// Create a [`ZipStrIter`] over `s`, which yields the substring of
+    /// `s` along with each item, i.e. `(kind, range, &s[range])`.
+    ///
+    /// The `end` is `s.len()`.
+    ///
+    /// [`ZipStrIter`]: struct.ZipStrIter.html
+    ///
+    /// # Panics
+    ///
+    /// Panics immediately if any range is outside `0..s.len()`, or is
+    /// not on char boundaries. Thereby the ranges are iterated twice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![4..7]
+    ///     .into_iter()
+    ///     .every_range_zip_str("Foo Bar Baz")
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     items,
+    ///     [(Excluded, 0..4, "Foo "), (Included, 4..7, "Bar"), (Excluded, 7..11, " Baz")]
+    /// );
+    /// ```
+    #[inline]
+    fn every_range_zip_str(self, s: &str) -> ZipStrIter<'_, Self>
+    where
+        Self: Clone,
+    {
+        ZipStrIter::new(self, s)
+    }
+}
+
+impl<T> EveryRange for T where T: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use std::iter;
+
+    use super::*;
+
+    #[test]
+    fn kind_methods() {
+        use EveryRangeKind::*;
+
+        assert!(Included.is_included());
+        assert!(!Included.is_excluded());
+        assert!(Excluded.is_excluded());
+        assert!(!Excluded.is_included());
+
+        assert_eq!(Included.as_str(), "included");
+        assert_eq!(Excluded.as_str(), "excluded");
+
+        assert_eq!(Included.toggle(), Excluded);
+        assert_eq!(Excluded.toggle(), Included);
+        assert_eq!(Included.toggle().toggle(), Included);
+    }
+
+    #[test]
+    fn skip_leading() {
+        let items = iter::once(5..10)
+            .every_range_no_leading(15)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            items,
+            [
+                (EveryRangeKind::Included, 5..10),
+                (EveryRangeKind::Excluded, 10..15)
+            ]
+        );
+
+        let items = [0..2, 4..6]
+            .iter()
+            .cloned()
+            .every_range_no_leading(6)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            items,
+            [0..2, 4..6]
+                .iter()
+                .cloned()
+                .every_range(6)
+                .collect::<Vec<_>>()
+        );
+
+        let mut iter = [2..4, 6..8].iter().cloned().every_range(10);
+        assert_eq!(iter.next(), Some((EveryRangeKind::Excluded, 0..2)));
+        assert_eq!(iter.next(), Some((EveryRangeKind::Included, 2..4)));
+
+        let items = iter.skip_leading().collect::<Vec<_>>();
+        assert_eq!(
+            items,
+            [
+                (EveryRangeKind::Included, 6..8),
+                (EveryRangeKind::Excluded, 8..10)
+            ]
+        );
+
+        let count = iter::empty().every_range_no_leading(10).count();
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn skip_trailing() {
+        let items = iter::once(2..5)
+            .every_range_no_trailing(10)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            items,
+            [
+                (EveryRangeKind::Excluded, 0..2),
+                (EveryRangeKind::Included, 2..5)
+            ]
+        );
+
+        let ranges = [2..4, 6..8];
+        let every_range = || ranges.iter().cloned().every_range(10);
+
+        let items = every_range().skip_trailing().collect::<Vec<_>>();
+        let expected = every_range().collect::<Vec<_>>();
+        assert_eq!(items, expected[..4]);
+
+        let items = every_range().skip_trailing().rev().collect::<Vec<_>>();
+        let expected = every_range().rev().collect::<Vec<_>>();
+        assert_eq!(items, expected[1..]);
+
+        let items = every_range()
+            .skip_leading()
+            .skip_trailing()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            items,
+            expected[1..4].iter().rev().cloned().collect::<Vec<_>>()
+        );
+
+        let items = [2..4, 6..10]
+            .iter()
+            .cloned()
+            .every_range_no_trailing(10)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            items,
+            [2..4, 6..10]
+                .iter()
+                .cloned()
+                .every_range(10)
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(iter::empty().every_range_no_trailing(10).count(), 0);
+        assert_eq!(iter::empty().every_range_no_trailing(10).rev().count(), 0);
+    }
+
+    #[test]
+    fn current_index() {
+        let mut iter = [2..4, 4..6, 8..9].iter().cloned().every_range(10);
+
+        let mut indices = vec![iter.current_index()];
+        while iter.next().is_some() {
+            indices.push(iter.current_index());
+            assert_eq!(iter.end(), 10);
+            assert_eq!(iter.remaining_range(), iter.current_index()..10);
+        }
+
+        assert_eq!(indices, [0, 2, 4, 6, 8, 9, 10]);
+
+        // `next` is pending after the `Excluded` range
+        let mut iter = iter::once(2..4).every_range(10);
+        assert_eq!(iter.next(), Some((EveryRangeKind::Excluded, 0..2)));
+        assert!(iter.next.is_some());
+        assert_eq!(iter.current_index(), 2);
+        assert_eq!(iter.remaining_range(), 2..10);
+    }
+
+    #[test]
+    fn fold() {
+        let text = "Foo12Bar34Baz56";
+
+        let ranges = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .collect::<Vec<_>>();
+
+        let shapes: [(&[Range<usize>], usize); 5] = [
+            (&[], 0),
+            (&[], 10),
+            (&[0..2, 2..4], 4),
+            (&[2..4, 6..8], 10),
+            (&ranges, text.len()),
+        ];
+
+        for &(ranges, end) in shapes.iter() {
+            let iter = ranges.iter().cloned().every_range(end);
+
+            for n in 0..4 {
+                let mut iter = iter.clone();
+                iter.by_ref().take(n).for_each(|_| {});
+
+                let expected = iter.clone().collect::<Vec<_>>();
+                let actual = iter.clone().fold(vec![], |mut v, x| {
+                    v.push(x);
+                    v
+                });
+                assert_eq!(actual, expected);
+
+                let expected = iter.clone().skip_trailing().collect::<Vec<_>>();
+                let actual = iter.clone().skip_trailing().fold(vec![], |mut v, x| {
+                    v.push(x);
+                    v
+                });
+                assert_eq!(actual, expected);
+            }
 
-use std::iter::FusedIterator;
-use std::ops::Range;
+            let mut iter = iter.clone();
+            if iter.next_back().is_some() {
+                let expected = iter.clone().collect::<Vec<_>>();
+                let actual = iter.fold(vec![], |mut v, x| {
+                    v.push(x);
+                    v
+                });
+                assert_eq!(actual, expected);
+            }
+        }
+    }
 
-/// `EveryRangeKind` can be used to distinguish original input
-/// ranges from generates ranges.
-#[derive(PartialEq, Clone, Copy, Debug)]
-pub enum EveryRangeKind {
-    /// `Included` ranges are the ones produces by the inner [`Iterator`].
-    ///
-    /// [`Iterator`]: https://doc.rust-lang.org/stable/std/iter/trait.Iterator.html
-    Included,
+    #[test]
+    #[should_panic = "assertion failed: self.index <= next.start"]
+    fn fold_out_of_order() {
+        [4..6, 0..2]
+            .iter()
+            .cloned()
+            .every_range(10)
+            .for_each(|_| {});
+    }
 
-    /// Excluded ranges are the ones generated dynamically by [`EveryRangeIter`].
-    ///
-    /// [`EveryRangeIter`]: struct.EveryRangeIter.html
-    Excluded,
-}
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn count() {
+        let shapes: [(&[Range<usize>], usize); 7] = [
+            (&[], 0),
+            (&[], 10),
+            (&[4..6], 10),
+            (&[0..10], 10),
+            (&[0..2, 8..10], 10),
+            (&[0..2, 2..4, 4..4], 10),
+            (&[1..2, 4..6, 6..9], 9),
+        ];
 
-// TODO: EveryRangeIter is not very lenient, consider if `range.start > self.end` and `range.end > self.end` should stop the iterator, instead of panicking
-// TODO: The question is, if so, does it ignore the last range? does it clamp it? does it just return it anyways and stop after?
+        for &(ranges, end) in shapes.iter() {
+            let iter = ranges.iter().cloned().every_range(end);
 
-/// `EveryRangeIter` iterates over [`Range`]s and "fill in"
-/// missing ranges, i.e. the gap between two consecutive ranges.
-/// The original ranges and the generated ones,
-/// can be distinguished by the [`Included`] and
-/// [`Excluded`] enum variants.
-///
-/// [`EveryRangeIter`]: struct.EveryRangeIter.html
-/// [`Included`]: enum.EveryRangeKind.html#variant.Included
-/// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
-///
-/// [`Range`]: https://doc.rust-lang.org/stable/std/ops/struct.Range.html
-///
-/// # Panics
-///
-/// Currently, `EveryRangeIter` resorts to panicking
-/// in the following conditions. `EveryRangeIter` might
-/// be made more lenient in the future, if the behavior
-/// can be better consistently defined without panicking.
-///
-/// - Panics if [`Range`]s are received out of order.
-/// - Panics if [`Range`]s overlap.
-/// - Panics if any [`Range`] exceeds the `end` of the `EveryRangeIter`.
-#[allow(missing_debug_implementations)]
-pub struct EveryRangeIter<I>
-where
-    I: Iterator<Item = Range<usize>>,
-{
-    index: usize,
-    end: usize,
-    iter: I,
-    next: Option<Range<usize>>,
-}
+            assert_eq!(iter.clone().count(), iter.clone().collect::<Vec<_>>().len());
 
-impl<I> EveryRangeIter<I>
-where
-    I: Iterator<Item = Range<usize>>,
-{
-    /// Create an `EveryRangeIter` with an `iter` and `end`,
-    /// which represents the "end point". Thereby, if `end` is
-    /// greater than the last [`range.end`] then an ending
-    /// [`Excluded`] range is generated, otherwise no additional
-    /// ending range is generated.
-    ///
-    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
-    ///
-    /// [`range.end`]: https://doc.rust-lang.org/stable/std/ops/struct.Range.html#structfield.end
-    #[inline]
-    pub fn new(iter: I, end: usize) -> Self {
-        Self {
-            index: 0,
-            end,
-            iter,
-            next: None,
+            let mut iter = iter;
+            while iter.next().is_some() {
+                assert_eq!(iter.clone().count(), iter.clone().collect::<Vec<_>>().len());
+            }
         }
     }
-}
 
-impl<I> Iterator for EveryRangeIter<I>
-where
-    I: Iterator<Item = Range<usize>>,
-{
-    type Item = (EveryRangeKind, Range<usize>);
+    #[test]
+    fn nth() {
+        let shapes: [&[Range<usize>]; 5] = [
+            &[],
+            &[0..2, 2..4],
+            &[2..4, 6..8],
+            &[0..1, 3..3, 5..10],
+            &[1..2, 2..2, 4..6, 6..9],
+        ];
 
-    #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        if let Some(next) = self.next.take() {
-            self.index = next.end;
+        for ranges in shapes.iter() {
+            for &end in &[10, 12] {
+                let iter = ranges.iter().cloned().every_range(end);
 
-            Some((EveryRangeKind::Included, next))
-        } else if let Some(next) = self.iter.next() {
-            assert!(self.index <= next.start);
-            assert!(next.end <= self.end);
-            assert!(next.start <= next.end);
+                for n in 0..10 {
+                    let mut actual = iter.clone();
+                    let mut expected = iter.clone().skip(n);
 
-            if self.index < next.start {
-                let start = self.index;
-                self.index = next.start;
-                self.next = Some(next);
+                    assert_eq!(actual.nth(n), expected.next(), "{:?} {}", ranges, n);
+                    assert!(actual.eq(expected));
+                }
+            }
+        }
+    }
 
-                Some((EveryRangeKind::Excluded, start..self.index))
-            } else {
-                self.index = next.end;
+    #[test]
+    #[should_panic = "assertion failed: self.index <= next.start"]
+    fn nth_out_of_order() {
+        [4..6, 0..2].iter().cloned().every_range(10).nth(5);
+    }
 
-                Some((EveryRangeKind::Included, next))
+    #[test]
+    fn every_range_single() {
+        use EveryRangeKind::*;
+
+        let cases = [
+            (
+                2..4,
+                6,
+                vec![(Excluded, 0..2), (Included, 2..4), (Excluded, 4..6)],
+            ),
+            (0..4, 6, vec![(Included, 0..4), (Excluded, 4..6)]),
+            (2..6, 6, vec![(Excluded, 0..2), (Included, 2..6)]),
+            (0..6, 6, vec![(Included, 0..6)]),
+        ];
+
+        for (range, end, expected) in cases.iter().cloned() {
+            let actual = EveryRangeIter::every_range_single(range, end).collect::<Vec<_>>();
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn every_range_empty() {
+        let actual = EveryRangeIter::every_range_empty(6).collect::<Vec<_>>();
+        assert_eq!(actual, [(EveryRangeKind::Excluded, 0..6)]);
+
+        assert_eq!(EveryRangeIter::every_range_empty(0).count(), 0);
+    }
+
+    #[test]
+    fn size_hint() {
+        let text = "Foo12Bar34Baz56";
+
+        let ranges = || {
+            text.match_indices(char::is_numeric)
+                .map(|(start, part)| start..(start + part.len()))
+                .collect::<Vec<_>>()
+                .into_iter()
+        };
+
+        for &(a, b) in &[(0, 0), (3, 0), (0, 4), (2, 2), (5, 6)] {
+            let mut iter = ranges().every_range(text.len());
+            iter.by_ref().take(a).for_each(|_| {});
+            iter.by_ref().rev().take(b).for_each(|_| {});
+
+            loop {
+                let (lower, upper) = iter.size_hint();
+                let count = iter.clone().count();
+
+                assert!(lower <= count, "{} > {}", lower, count);
+                assert!(count <= upper.unwrap(), "{} < {}", upper.unwrap(), count);
+
+                if iter.next().is_none() {
+                    break;
+                }
             }
-        } else if self.index < self.end {
-            let start = self.index;
+        }
 
-            self.index = self.end;
+        let iter = Vec::new().into_iter().every_range(0);
+        assert_eq!(iter.size_hint(), (0, Some(1)));
 
-            Some((EveryRangeKind::Excluded, start..self.end))
-        } else {
-            None
+        let iter = Vec::new().into_iter().every_range(10);
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+    }
+
+    #[test]
+    fn size_hint_no_trailing() {
+        let iter = iter::empty().every_range_no_trailing(10);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.count(), 0);
+
+        for ranges in &[vec![2..4, 6..8], vec![0..2, 6..10], vec![4..4, 10..10]] {
+            for &(a, b) in &[(0, 0), (1, 0), (0, 1), (2, 1)] {
+                let mut iter = ranges.clone().into_iter().every_range_no_trailing(10);
+                iter.by_ref().take(a).for_each(|_| {});
+                iter.by_ref().rev().take(b).for_each(|_| {});
+
+                loop {
+                    let (lower, upper) = iter.size_hint();
+                    let count = iter.clone().count();
+
+                    assert!(lower <= count, "{} > {}", lower, count);
+                    assert!(count <= upper.unwrap(), "{} < {}", upper.unwrap(), count);
+
+                    if iter.next().is_none() {
+                        break;
+                    }
+                }
+            }
         }
     }
-}
 
-impl<I> FusedIterator for EveryRangeIter<I> where I: Iterator<Item = Range<usize>> {}
+    #[test]
+    fn debug() {
+        let mut iter = [2..4, 6..8].iter().cloned().every_range(10);
+        assert_eq!(iter.next(), Some((EveryRangeKind::Excluded, 0..2)));
 
-/// Trait which implements `every_range` to get a `EveryRangeIter`.
-///
-/// *[See `EveryRangeIter` for more information.][`EveryRangeIter`]*
-///
-/// [`every_range`]: trait.EveryRange.html#method.every_range
-/// [`EveryRangeIter`]: struct.EveryRangeIter.html
-pub trait EveryRange: Sized + Iterator<Item = Range<usize>> {
-    /// Create an [`EveryRangeIter`] with `end`, which represents
-    /// the "end point". Thereby, if `end` is greater than the last
-    /// [`range.end`] then an ending [`Excluded`] range is generated,
-    /// otherwise no additional ending range is generated.
-    ///
-    /// *[See `EveryRangeIter` for more information.][`EveryRangeIter`]*
-    ///
-    /// [`EveryRangeIter`]: struct.EveryRangeIter.html
-    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
-    ///
-    /// [`range.end`]: https://doc.rust-lang.org/stable/std/ops/struct.Range.html#structfield.end
-    #[inline]
-    fn every_range(self, end: usize) -> EveryRangeIter<Self> {
-        EveryRangeIter::new(self, end)
+        assert_eq!(
+            format!("{:?}", iter),
+            "EveryRangeIter { index: 2, end: 10, iter: <iterator>, next: Some(2..4), \
+             back_index: 10, back: None, skip_trailing: false }"
+        );
     }
-}
 
-impl<T> EveryRange for T where T: Iterator<Item = Range<usize>> {}
+    #[test]
+    fn clone_mid_traversal() {
+        let text = "Foo12Bar34Baz56";
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let mut iter = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len());
+
+        assert_eq!(iter.next(), Some((EveryRangeKind::Excluded, 0..3)));
+        assert_eq!(iter.next(), Some((EveryRangeKind::Included, 3..4)));
+
+        let mut clone = iter.clone();
+        assert_eq!(clone.next(), Some((EveryRangeKind::Included, 4..5)));
+        assert_eq!(clone.next(), Some((EveryRangeKind::Excluded, 5..8)));
+
+        let remaining = iter.collect::<Vec<_>>();
+        assert_eq!(remaining.len(), 7);
+        assert_eq!(remaining[2..], clone.collect::<Vec<_>>()[..]);
+    }
+
+    #[test]
+    fn kind_not_bool() {
+        use EveryRangeKind::*;
+
+        assert_eq!(!Included, Excluded);
+        assert_eq!(!Excluded, Included);
+
+        assert_eq!(EveryRangeKind::from(true), Included);
+        assert_eq!(EveryRangeKind::from(false), Excluded);
+
+        for &kind in &[Included, Excluded] {
+            assert_eq!(!!kind, kind);
+            assert_eq!(EveryRangeKind::from(bool::from(kind)), kind);
+
+            let b: bool = kind.into();
+            let not_b: bool = (!kind).into();
+            assert_eq!(!b, not_b);
+        }
+    }
+
+    #[test]
+    fn kind_default() {
+        assert_eq!(EveryRangeKind::default(), EveryRangeKind::Excluded);
+        assert_eq!(bool::from(EveryRangeKind::default()), bool::default());
+    }
+
+    #[test]
+    fn kind_hash_ord() {
+        use std::cmp::Ordering;
+        use std::collections::{HashMap, HashSet};
+        use EveryRangeKind::*;
+
+        let set = [Included, Excluded, Included]
+            .iter()
+            .cloned()
+            .collect::<HashSet<_>>();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&Included));
+        assert!(set.contains(&Excluded));
+
+        assert!(Included < Excluded);
+        for &(a, b) in &[
+            (Included, Included),
+            (Included, Excluded),
+            (Excluded, Included),
+            (Excluded, Excluded),
+        ] {
+            assert_eq!(a.partial_cmp(&b), Some(a.cmp(&b)));
+        }
+        assert_eq!(Excluded.cmp(&Included), Ordering::Greater);
+
+        let text = "Foo12Bar34Baz56";
+        let mut groups: HashMap<EveryRangeKind, Vec<Range<usize>>> = HashMap::new();
+        for (kind, range) in text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+        {
+            groups.entry(kind).or_default().push(range);
+        }
+        assert_eq!(groups[&Included].len(), 6);
+        assert_eq!(groups[&Excluded], [0..3, 5..8, 10..13]);
+
+        let mut items = vec![(Excluded, 0..2), (Included, 2..4), (Excluded, 4..6)];
+        items.sort_by_key(|&(kind, _)| kind);
+        assert_eq!(
+            items,
+            [(Included, 2..4), (Excluded, 0..2), (Excluded, 4..6)]
+        );
+    }
+
+    #[test]
+    fn kind_display_from_str() {
+        for &kind in &[EveryRangeKind::Included, EveryRangeKind::Excluded] {
+            assert_eq!(kind.to_string(), kind.as_str());
+            assert_eq!(kind.to_string().parse::<EveryRangeKind>().unwrap(), kind);
+            assert_eq!(kind.to_string().to_uppercase().parse(), Ok(kind));
+        }
+
+        assert_eq!(
+            "include".parse::<EveryRangeKind>(),
+            Err(ParseEveryRangeKindError(()))
+        );
+        assert!("".parse::<EveryRangeKind>().is_err());
+    }
 
     #[test]
     fn every_range_matches1() {
@@ -394,4 +1848,152 @@ mod tests {
             .every_range(20)
             .for_each(|_| {});
     }
+
+    #[test]
+    fn every_range_rev() {
+        let text = "Foo12Bar34Baz56";
+
+        for &pattern in &[char::is_alphabetic as fn(char) -> bool, char::is_numeric] {
+            let iter = || {
+                text.match_indices(pattern)
+                    .map(|(start, part)| start..(start + part.len()))
+                    .collect::<Vec<_>>()
+                    .into_iter()
+                    .every_range(text.len())
+            };
+
+            let expected = iter().collect::<Vec<_>>();
+            let actual = iter()
+                .rev()
+                .collect::<Vec<_>>()
+                .into_iter()
+                .rev()
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn every_range_rev_mixed() {
+        let ranges = [0..0, 2..4, 4..5, 7..8, 10..10];
+
+        let expected = ranges.iter().cloned().every_range(12).collect::<Vec<_>>();
+
+        for front in 0..=expected.len() {
+            let mut iter = ranges.iter().cloned().every_range(12);
+
+            let mut head = Vec::new();
+            let mut tail = Vec::new();
+
+            for i in 0.. {
+                let item = if (i < front) || (i % 2 == 0) {
+                    iter.next().map(|item| head.push(item))
+                } else {
+                    iter.next_back().map(|item| tail.push(item))
+                };
+
+                if item.is_none() {
+                    break;
+                }
+            }
+
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+
+            head.extend(tail.into_iter().rev());
+            assert_eq!(head, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic = "assertion failed: next.end <= self.end"]
+    fn range_end_after_end_rev() {
+        [0..2, 4..6]
+            .iter()
+            .cloned()
+            .every_range(5)
+            .rev()
+            .for_each(|_| {});
+    }
+
+    #[test]
+    #[should_panic = "assertion failed: next.end <= self.back_index"]
+    fn ranges_out_of_order_rev() {
+        [4..6, 0..2, 8..10]
+            .iter()
+            .cloned()
+            .every_range(20)
+            .rev()
+            .for_each(|_| {});
+    }
+
+    #[test]
+    fn every_range_from_second_line() {
+        let text = "Foo 12\nBar 34 Baz\n56";
+
+        let start = text.find('\n').unwrap() + 1;
+        let end = start + text[start..].find('\n').unwrap();
+
+        use EveryRangeKind::*;
+        let actual = text
+            .match_indices(char::is_alphabetic)
+            .map(|(start, part)| start..(start + part.len()))
+            .skip_while(|range| range.start < start)
+            .take_while(|range| range.end <= end)
+            .every_range_from(start, end)
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Included, "B"),
+                (Included, "a"),
+                (Included, "r"),
+                (Excluded, " 34 "),
+                (Included, "B"),
+                (Included, "a"),
+                (Included, "z"),
+            ]
+        );
+    }
+
+    #[test]
+    fn every_range_from_leading_excluded() {
+        use EveryRangeKind::*;
+
+        let actual = std::iter::once(5..6)
+            .every_range_from(3, 6)
+            .collect::<Vec<_>>();
+        assert_eq!(actual, [(Excluded, 3..5), (Included, 5..6)]);
+
+        let actual = std::iter::once(3..4)
+            .every_range_from(3, 6)
+            .collect::<Vec<_>>();
+        assert_eq!(actual, [(Included, 3..4), (Excluded, 4..6)]);
+
+        let actual = std::iter::empty()
+            .every_range_from(6, 6)
+            .collect::<Vec<_>>();
+        assert_eq!(actual, []);
+    }
+
+    #[test]
+    #[should_panic = "assertion failed: self.index <= next.start"]
+    fn every_range_from_range_before_start() {
+        [0..2, 4..6]
+            .iter()
+            .cloned()
+            .every_range_from(3, 10)
+            .for_each(|_| {});
+    }
+
+    #[test]
+    #[should_panic = "assertion failed: start <= end"]
+    fn every_range_from_start_after_end() {
+        std::iter::once(4..6)
+            .every_range_from(7, 6)
+            .for_each(|_| {});
+    }
 }
@@ -0,0 +1,384 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `AtMostNOfEachIter` caps the amount of items of each kind.
+///
+/// This `struct` is created by the [`emit_at_most_n_of_each_kind`]
+/// method on [`EveryRangeIter`].
+///
+/// [`emit_at_most_n_of_each_kind`]: struct.EveryRangeIter.html#method.emit_at_most_n_of_each_kind
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct AtMostNOfEachIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+    included_left: usize,
+    excluded_left: usize,
+    drop_excess: bool,
+}
+
+impl<I> fmt::Debug for AtMostNOfEachIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AtMostNOfEachIter")
+            .field("iter", &self.iter)
+            .field("included_left", &self.included_left)
+            .field("excluded_left", &self.excluded_left)
+            .field("drop_excess", &self.drop_excess)
+            .finish()
+    }
+}
+
+impl<I> AtMostNOfEachIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Drop the items exceeding the cap of their kind,
+    /// instead of converting them into the other kind.
+    ///
+    /// *Note that the yielded ranges then no longer cover `0..end`.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![2..4, 6..8]
+    ///     .into_iter()
+    ///     .every_range(10)
+    ///     .emit_at_most_n_of_each_kind(1)
+    ///     .drop_excess()
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Excluded, 0..2), (Included, 2..4)]);
+    /// ```
+    #[inline]
+    pub fn drop_excess(mut self) -> Self {
+        self.drop_excess = true;
+        self
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create an [`AtMostNOfEachIter`], which yields at most `n` [`Included`]
+    /// items and at most `n` [`Excluded`] items.
+    ///
+    /// Once the cap of a kind is reached, the following items of that kind
+    /// are converted into the other kind. Alternatively, they can be dropped
+    /// by calling [`drop_excess`].
+    ///
+    /// [`AtMostNOfEachIter`]: struct.AtMostNOfEachIter.html
+    /// [`drop_excess`]: struct.AtMostNOfEachIter.html#method.drop_excess
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![2..4, 6..8]
+    ///     .into_iter()
+    ///     .every_range(10)
+    ///     .emit_at_most_n_of_each_kind(1)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     items,
+    ///     [
+    ///         (Excluded, 0..2),
+    ///         (Included, 2..4),
+    ///         (Included, 4..6),
+    ///         (Excluded, 6..8),
+    ///         (Included, 8..10),
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    pub fn emit_at_most_n_of_each_kind(self, n: usize) -> AtMostNOfEachIter<I> {
+        AtMostNOfEachIter {
+            iter: self,
+            included_left: n,
+            excluded_left: n,
+            drop_excess: false,
+        }
+    }
+}
+
+impl<I> Iterator for AtMostNOfEachIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (kind, range) in &mut self.iter {
+            let (left, other) = match kind {
+                EveryRangeKind::Included => (&mut self.included_left, EveryRangeKind::Excluded),
+                EveryRangeKind::Excluded => (&mut self.excluded_left, EveryRangeKind::Included),
+            };
+
+            if *left > 0 {
+                *left -= 1;
+                return Some((kind, range));
+            } else if !self.drop_excess {
+                return Some((other, range));
+            }
+        }
+
+        None
+    }
+}
+
+impl<I> FusedIterator for AtMostNOfEachIter<I> where I: Iterator<Item = Range<usize>> {}
+
+/// `CountDownIter` counts down the items of each kind, and absorbs
+/// the items of a kind into their neighbors once its count reaches `0`.
+///
+/// This `struct` is created by the [`per_kind_count_down`]
+/// method on [`EveryRangeIter`].
+///
+/// [`per_kind_count_down`]: struct.EveryRangeIter.html#method.per_kind_count_down
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct CountDownIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+    included_left: usize,
+    excluded_left: usize,
+    pending: Option<(EveryRangeKind, Range<usize>)>,
+    /// Whether `pending` has absorbed any items.
+    absorbed: bool,
+}
+
+impl<I> fmt::Debug for CountDownIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CountDownIter")
+            .field("iter", &self.iter)
+            .field("included_left", &self.included_left)
+            .field("excluded_left", &self.excluded_left)
+            .field("pending", &self.pending)
+            .field("absorbed", &self.absorbed)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`CountDownIter`], which yields at most `included_limit`
+    /// [`Included`] items and at most `excluded_limit` [`Excluded`] items.
+    ///
+    /// Unlike [`emit_at_most_n_of_each_kind`], the limits are independent,
+    /// and once the count of a kind reaches `0`, the following items of
+    /// that kind are absorbed into the preceding item. Thereby the yielded
+    /// ranges still cover `0..end`, but without yielding additional items.
+    ///
+    /// If both counts reach `0`, then all remaining ranges are absorbed
+    /// into the last yielded item.
+    ///
+    /// [`CountDownIter`]: struct.CountDownIter.html
+    /// [`emit_at_most_n_of_each_kind`]: struct.EveryRangeIter.html#method.emit_at_most_n_of_each_kind
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![2..4, 6..8]
+    ///     .into_iter()
+    ///     .every_range(10)
+    ///     .per_kind_count_down(1, 3)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Excluded, 0..2), (Included, 2..4), (Excluded, 4..10)]);
+    /// ```
+    #[inline]
+    pub fn per_kind_count_down(
+        self,
+        included_limit: usize,
+        excluded_limit: usize,
+    ) -> CountDownIter<I> {
+        CountDownIter {
+            iter: self,
+            included_left: included_limit,
+            excluded_left: excluded_limit,
+            pending: None,
+            absorbed: false,
+        }
+    }
+}
+
+impl<I> CountDownIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Count down `kind`, returning `false` if its count already reached `0`.
+    #[inline]
+    fn count_down(&mut self, kind: EveryRangeKind) -> bool {
+        let left = match kind {
+            EveryRangeKind::Included => &mut self.included_left,
+            EveryRangeKind::Excluded => &mut self.excluded_left,
+        };
+
+        if *left > 0 {
+            *left -= 1;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<I> Iterator for CountDownIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((kind, range)) = self.iter.next() {
+            match &mut self.pending {
+                Some((pending_kind, pending)) if self.absorbed && (*pending_kind == kind) => {
+                    pending.end = range.end;
+                    continue;
+                }
+                _ => {}
+            }
+
+            if self.count_down(kind) {
+                self.absorbed = false;
+                let item = self.pending.replace((kind, range));
+                if item.is_some() {
+                    return item;
+                }
+            } else if let Some((_, pending)) = &mut self.pending {
+                pending.end = range.end;
+                self.absorbed = true;
+            } else {
+                let other = kind.toggle();
+                if self.count_down(other) {
+                    self.pending = Some((other, range));
+                    self.absorbed = true;
+                }
+            }
+        }
+
+        self.pending.take()
+    }
+}
+
+impl<I> FusedIterator for CountDownIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn emit_at_most_n_of_each_kind() {
+        let text = "Foo12Bar34Baz56";
+
+        let ranges = || {
+            text.match_indices(char::is_numeric)
+                .map(|(start, part)| start..(start + part.len()))
+        };
+
+        let kinds = ranges()
+            .every_range(text.len())
+            .emit_at_most_n_of_each_kind(2)
+            .map(|(kind, _)| kind)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [
+                Excluded, Included, Included, Excluded, Excluded, Excluded, Included, Excluded,
+                Excluded,
+            ]
+        );
+
+        let items = ranges()
+            .every_range(text.len())
+            .emit_at_most_n_of_each_kind(2)
+            .drop_excess()
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            items,
+            [
+                (Excluded, "Foo"),
+                (Included, "1"),
+                (Included, "2"),
+                (Excluded, "Bar"),
+            ]
+        );
+    }
+
+    #[test]
+    fn per_kind_count_down() {
+        let text = "Foo12Bar34Baz56";
+
+        let ranges = || {
+            text.match_indices(char::is_numeric)
+                .map(|(start, part)| start..(start + part.len()))
+        };
+
+        let items = ranges()
+            .every_range(text.len())
+            .per_kind_count_down(1, 2)
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            items,
+            [
+                (Excluded, "Foo"),
+                (Included, "12"),
+                (Excluded, "Bar34Baz56"),
+            ]
+        );
+
+        let items = ranges()
+            .every_range(text.len())
+            .per_kind_count_down(0, 1)
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+        assert_eq!(items, [(Excluded, "Foo12Bar34Baz56")]);
+
+        let count = ranges()
+            .every_range(text.len())
+            .per_kind_count_down(0, 0)
+            .count();
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn per_kind_count_down_unlimited() {
+        let ranges = [2..4, 6..8];
+
+        let actual = ranges
+            .iter()
+            .cloned()
+            .every_range(10)
+            .per_kind_count_down(usize::MAX, usize::MAX)
+            .collect::<Vec<_>>();
+        let expected = ranges.iter().cloned().every_range(10).collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+}
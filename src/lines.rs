@@ -0,0 +1,250 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `LineRangesIter` converts the byte ranges of an [`EveryRangeIter`]
+/// into ranges relative to the lines of a text.
+///
+/// This `struct` is created by the [`into_line_ranges`]
+/// method on [`EveryRangeIter`].
+///
+/// [`into_line_ranges`]: struct.EveryRangeIter.html#method.into_line_ranges
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct LineRangesIter<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+    text: &'a str,
+    line: usize,
+    line_start: usize,
+    byte_pos: usize,
+    pending: Option<(EveryRangeKind, Range<usize>)>,
+}
+
+impl<'a, I> fmt::Debug for LineRangesIter<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LineRangesIter")
+            .field("iter", &self.iter)
+            .field("text", &self.text)
+            .field("line", &self.line)
+            .field("line_start", &self.line_start)
+            .field("byte_pos", &self.byte_pos)
+            .field("pending", &self.pending)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`LineRangesIter`], which yields
+    /// `(EveryRangeKind, usize, Range<usize>)`, where the `usize`
+    /// is the 0-based line number and the range is relative to the
+    /// start of that line.
+    ///
+    /// Ranges spanning multiple lines are split into one item per line.
+    /// The `'\n'` terminating a line is considered part of that line.
+    ///
+    /// [`LineRangesIter`]: struct.LineRangesIter.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let text = "Foo\nBar\nBaz";
+    ///
+    /// let lines = vec![2..5]
+    ///     .into_iter()
+    ///     .every_range(text.len())
+    ///     .into_line_ranges(text)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     lines,
+    ///     [
+    ///         (Excluded, 0, 0..2),
+    ///         (Included, 0, 2..4),
+    ///         (Included, 1, 0..1),
+    ///         (Excluded, 1, 1..4),
+    ///         (Excluded, 2, 0..3),
+    ///     ]
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any range is out of bounds of `text`.
+    #[inline]
+    pub fn into_line_ranges(self, text: &str) -> LineRangesIter<'_, I> {
+        LineRangesIter {
+            iter: self,
+            text,
+            line: 0,
+            line_start: 0,
+            byte_pos: 0,
+            pending: None,
+        }
+    }
+}
+
+impl<'a, I> Iterator for LineRangesIter<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, usize, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (kind, range) = match self.pending.take() {
+            Some(pending) => pending,
+            None => self.iter.next()?,
+        };
+
+        let bytes = self.text.as_bytes();
+
+        // Account for any lines before the first item, e.g. when using
+        // `every_range_from`, `skip_leading` or a partially consumed iterator
+        for (i, &b) in bytes[self.byte_pos..range.start].iter().enumerate() {
+            if b == b'\n' {
+                self.line += 1;
+                self.line_start = self.byte_pos + i + 1;
+            }
+        }
+
+        let line = self.line;
+        let start = range.start - self.line_start;
+
+        let newline = bytes[range.clone()].iter().position(|&b| b == b'\n');
+
+        if let Some(i) = newline {
+            let split = range.start + i + 1;
+            let end = split - self.line_start;
+
+            self.line += 1;
+            self.line_start = split;
+            self.byte_pos = split;
+
+            if split < range.end {
+                self.pending = Some((kind, split..range.end));
+            }
+
+            Some((kind, line, start..end))
+        } else {
+            self.byte_pos = range.end;
+            Some((kind, line, start..(range.end - self.line_start)))
+        }
+    }
+}
+
+impl<'a, I> FusedIterator for LineRangesIter<'a, I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn into_line_ranges() {
+        let text = "Foo rust-lang.org Bar\nBaz crates.io Qux";
+
+        let actual = vec![4..17, 26..35]
+            .into_iter()
+            .every_range(text.len())
+            .into_line_ranges(text)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Excluded, 0, 0..4),
+                (Included, 0, 4..17),
+                (Excluded, 0, 17..22),
+                (Excluded, 1, 0..4),
+                (Included, 1, 4..13),
+                (Excluded, 1, 13..17),
+            ]
+        );
+    }
+
+    #[test]
+    fn into_line_ranges_multiple_lines() {
+        let text = "a\n\nb\nc";
+
+        let actual = std::iter::once(1..6)
+            .every_range(text.len())
+            .into_line_ranges(text)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Excluded, 0, 0..1),
+                (Included, 0, 1..2),
+                (Included, 1, 0..1),
+                (Included, 2, 0..2),
+                (Included, 3, 0..1),
+            ]
+        );
+    }
+
+    #[test]
+    fn into_line_ranges_from() {
+        let text = "aa\nbb X\ncc";
+
+        let actual = std::iter::once(6..7)
+            .every_range_from(3, text.len())
+            .into_line_ranges(text)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Excluded, 1, 0..3),
+                (Included, 1, 3..4),
+                (Excluded, 1, 4..5),
+                (Excluded, 2, 0..2),
+            ]
+        );
+    }
+
+    #[test]
+    fn into_line_ranges_advanced() {
+        let text = "aa\nbb X\ncc";
+
+        let mut iter = std::iter::once(6..7).every_range(text.len());
+        assert_eq!(iter.next(), Some((Excluded, 0..6)));
+
+        let actual = iter.into_line_ranges(text).collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Included, 1, 3..4),
+                (Excluded, 1, 4..5),
+                (Excluded, 2, 0..2)
+            ]
+        );
+
+        let actual = std::iter::once(6..7)
+            .every_range(text.len())
+            .skip_leading()
+            .into_line_ranges(text)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Included, 1, 3..4),
+                (Excluded, 1, 4..5),
+                (Excluded, 2, 0..2)
+            ]
+        );
+    }
+}
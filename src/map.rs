@@ -0,0 +1,382 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use crate::{DebugPlaceholder, EveryRangeIter, EveryRangeKind};
+
+/// `FallibleRangeMapIter` maps every range using a fallible closure.
+///
+/// This `struct` is created by the [`map_ranges_fallible`]
+/// method on [`EveryRangeIter`].
+///
+/// [`map_ranges_fallible`]: struct.EveryRangeIter.html#method.map_ranges_fallible
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct FallibleRangeMapIter<I, F, E>
+where
+    I: Iterator<Item = Range<usize>>,
+    F: FnMut(Range<usize>) -> Result<Range<usize>, E>,
+{
+    iter: EveryRangeIter<I>,
+    f: F,
+    phantom: PhantomData<fn() -> E>,
+}
+
+impl<I, F, E> fmt::Debug for FallibleRangeMapIter<I, F, E>
+where
+    I: Iterator<Item = Range<usize>>,
+    F: FnMut(Range<usize>) -> Result<Range<usize>, E>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FallibleRangeMapIter")
+            .field("iter", &self.iter)
+            .field("f", &DebugPlaceholder("<closure>"))
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`FallibleRangeMapIter`], which maps every range
+    /// using `f`, and yields `Result<(EveryRangeKind, Range<usize>), E>`.
+    ///
+    /// If `f` returns `Err`, then the error is yielded in place of the
+    /// item. Iteration can continue after an error.
+    ///
+    /// [`FallibleRangeMapIter`]: struct.FallibleRangeMapIter.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let text = "Føø";
+    ///
+    /// let items = vec![1..2]
+    ///     .into_iter()
+    ///     .every_range(text.len())
+    ///     .map_ranges_fallible(|range| {
+    ///         if text.is_char_boundary(range.start) && text.is_char_boundary(range.end) {
+    ///             Ok(range)
+    ///         } else {
+    ///             Err(range)
+    ///         }
+    ///     })
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [Ok((Excluded, 0..1)), Err(1..2), Err(2..5)]);
+    /// ```
+    #[inline]
+    pub fn map_ranges_fallible<F, E>(self, f: F) -> FallibleRangeMapIter<I, F, E>
+    where
+        F: FnMut(Range<usize>) -> Result<Range<usize>, E>,
+    {
+        FallibleRangeMapIter {
+            iter: self,
+            f,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<I, F, E> Iterator for FallibleRangeMapIter<I, F, E>
+where
+    I: Iterator<Item = Range<usize>>,
+    F: FnMut(Range<usize>) -> Result<Range<usize>, E>,
+{
+    type Item = Result<(EveryRangeKind, Range<usize>), E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (kind, range) = self.iter.next()?;
+        Some((self.f)(range).map(|range| (kind, range)))
+    }
+}
+
+impl<I, F, E> FusedIterator for FallibleRangeMapIter<I, F, E>
+where
+    I: Iterator<Item = Range<usize>>,
+    F: FnMut(Range<usize>) -> Result<Range<usize>, E>,
+{
+}
+
+/// `MapByKindIter` maps the ranges of each kind using a separate closure.
+///
+/// This `struct` is created by the [`map_by_kind`]
+/// method on [`EveryRangeIter`].
+///
+/// [`map_by_kind`]: struct.EveryRangeIter.html#method.map_by_kind
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct MapByKindIter<I, F, G, B>
+where
+    I: Iterator<Item = Range<usize>>,
+    F: FnMut(Range<usize>) -> B,
+    G: FnMut(Range<usize>) -> B,
+{
+    iter: EveryRangeIter<I>,
+    on_included: F,
+    on_excluded: G,
+}
+
+impl<I, F, G, B> fmt::Debug for MapByKindIter<I, F, G, B>
+where
+    I: Iterator<Item = Range<usize>>,
+    F: FnMut(Range<usize>) -> B,
+    G: FnMut(Range<usize>) -> B,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapByKindIter")
+            .field("iter", &self.iter)
+            .field("on_included", &DebugPlaceholder("<closure>"))
+            .field("on_excluded", &DebugPlaceholder("<closure>"))
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`MapByKindIter`], which maps the ranges of [`Included`]
+    /// items using `on_included`, and the ranges of [`Excluded`] items
+    /// using `on_excluded`.
+    ///
+    /// [`MapByKindIter`]: struct.MapByKindIter.html
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use every_range::EveryRange;
+    ///
+    /// let text = "Foo rust-lang.org Bar
+    /// Baz crates.io Qux";
+    ///
+    /// let output = vec![4..17, 26..35]
+    ///     .into_iter()
+    ///     .every_range(text.len())
+    ///     .map_by_kind(
+    ///         |range| format!("<a href=\"{0}\">{0}</a>", &text[range]).into(),
+    ///         |range| Cow::Borrowed(&text[range]),
+    ///     )
+    ///     .collect::<String>();
+    ///
+    /// assert_eq!(
+    ///     output,
+    ///     "Foo <a href=\"rust-lang.org\">rust-lang.org</a> Bar
+    /// Baz <a href=\"crates.io\">crates.io</a> Qux"
+    /// );
+    /// ```
+    #[inline]
+    pub fn map_by_kind<F, G, B>(self, on_included: F, on_excluded: G) -> MapByKindIter<I, F, G, B>
+    where
+        F: FnMut(Range<usize>) -> B,
+        G: FnMut(Range<usize>) -> B,
+    {
+        MapByKindIter {
+            iter: self,
+            on_included,
+            on_excluded,
+        }
+    }
+}
+
+impl<I, F, G, B> Iterator for MapByKindIter<I, F, G, B>
+where
+    I: Iterator<Item = Range<usize>>,
+    F: FnMut(Range<usize>) -> B,
+    G: FnMut(Range<usize>) -> B,
+{
+    type Item = B;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (kind, range) = self.iter.next()?;
+
+        Some(match kind {
+            EveryRangeKind::Included => (self.on_included)(range),
+            EveryRangeKind::Excluded => (self.on_excluded)(range),
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F, G, B> FusedIterator for MapByKindIter<I, F, G, B>
+where
+    I: Iterator<Item = Range<usize>>,
+    F: FnMut(Range<usize>) -> B,
+    G: FnMut(Range<usize>) -> B,
+{
+}
+
+/// `IntoRangesIter` yields the ranges of an [`EveryRangeIter`],
+/// without the kind.
+///
+/// This `struct` is created by the [`into_ranges`]
+/// method on [`EveryRangeIter`].
+///
+/// [`into_ranges`]: struct.EveryRangeIter.html#method.into_ranges
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct IntoRangesIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+}
+
+impl<I> fmt::Debug for IntoRangesIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntoRangesIter")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create an [`IntoRangesIter`], which yields the ranges of all
+    /// items, without the kind. The ranges thereby form a contiguous
+    /// partition of the remaining `index..end`.
+    ///
+    /// [`IntoRangesIter`]: struct.IntoRangesIter.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::EveryRange;
+    ///
+    /// let ranges = vec![2..4, 6..8]
+    ///     .into_iter()
+    ///     .every_range(10)
+    ///     .into_ranges()
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(ranges, [0..2, 2..4, 4..6, 6..8, 8..10]);
+    /// ```
+    #[inline]
+    pub fn into_ranges(self) -> IntoRangesIter<I> {
+        IntoRangesIter { iter: self }
+    }
+}
+
+impl<I> Iterator for IntoRangesIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = Range<usize>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, range)| range)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> FusedIterator for IntoRangesIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn map_ranges_fallible() {
+        let text = "Foo Bår Baz";
+
+        // Convert byte ranges into char ranges
+        let to_chars = |range: std::ops::Range<usize>| {
+            let char_index = |i| {
+                text.char_indices()
+                    .map(|(i, _)| i)
+                    .chain(Some(text.len()))
+                    .position(|pos| pos == i)
+                    .ok_or(i)
+            };
+            Ok(char_index(range.start)?..char_index(range.end)?)
+        };
+
+        let actual = [4..8, 9..12]
+            .iter()
+            .cloned()
+            .every_range(text.len())
+            .map_ranges_fallible(to_chars)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            [
+                Ok((Excluded, 0..4)),
+                Ok((Included, 4..7)),
+                Ok((Excluded, 7..8)),
+                Ok((Included, 8..11)),
+            ]
+        );
+
+        let actual = std::iter::once(4..6)
+            .every_range(text.len())
+            .map_ranges_fallible(to_chars)
+            .collect::<Vec<_>>();
+        assert_eq!(actual, [Ok((Excluded, 0..4)), Err(6), Err(6)]);
+    }
+
+    #[test]
+    fn map_by_kind() {
+        let text = "Foo12Bar34Baz56";
+
+        let (mut included, mut excluded) = (0, 0);
+
+        let actual = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .map_by_kind(
+                |range| {
+                    included += 1;
+                    text[range].parse::<u32>().unwrap().to_string()
+                },
+                |range| {
+                    excluded += 1;
+                    text[range].to_uppercase()
+                },
+            )
+            .collect::<String>();
+
+        assert_eq!(actual, "FOO12BAR34BAZ56");
+        assert_eq!((included, excluded), (6, 3));
+    }
+
+    #[test]
+    fn into_ranges() {
+        let text = "Foo12Bar34Baz56";
+
+        let ranges = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .into_ranges()
+            .collect::<Vec<_>>();
+
+        assert_eq!(ranges.len(), 9);
+        assert_eq!(ranges.first().map(|range| range.start), Some(0));
+        assert_eq!(ranges.last().map(|range| range.end), Some(text.len()));
+        for pair in ranges.windows(2) {
+            assert_eq!(pair[0].end, pair[1].start);
+        }
+    }
+}
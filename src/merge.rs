@@ -0,0 +1,227 @@
+use std::ops::Range;
+
+/// Sort `ranges` by `start` and merge overlapping ranges. If `adjacent`
+/// is `true`, then ranges where `b.start == a.end` are also merged.
+pub(crate) fn merge_ranges(mut ranges: Vec<Range<usize>>, adjacent: bool) -> Vec<Range<usize>> {
+    ranges.sort_by_key(|range| (range.start, range.end));
+
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if (range.start < last.end) || (adjacent && (range.start == last.end)) => {
+                last.end = last.end.max(range.end);
+            }
+            _ => merged.push(range),
+        }
+    }
+
+    merged
+}
+
+/// Sort `ranges` by `start` and merge all overlapping ranges, i.e. any
+/// pair where `b.start < a.end`, extending `a.end` if needed.
+///
+/// The returned ranges are sorted and non-overlapping, and thereby safe
+/// to pass to [`every_range`]. Reversed ranges, i.e. where `start > end`,
+/// are discarded.
+///
+/// *See [`merge_adjacent`] to also merge adjacent ranges.*
+///
+/// [`every_range`]: trait.EveryRange.html#method.every_range
+/// [`merge_adjacent`]: fn.merge_adjacent.html
+///
+/// # Example
+///
+/// ```
+/// use every_range::merge_overlapping;
+///
+/// let ranges = merge_overlapping(vec![6..8, 0..3, 2..4, 4..5]);
+/// assert_eq!(ranges, [0..4, 4..5, 6..8]);
+/// ```
+pub fn merge_overlapping(iter: impl IntoIterator<Item = Range<usize>>) -> Vec<Range<usize>> {
+    merge_ranges(forward_ranges(iter), false)
+}
+
+/// Sort `ranges` by `start` and merge all overlapping and adjacent ranges,
+/// i.e. any pair where `b.start <= a.end`, extending `a.end` if needed.
+///
+/// The returned ranges are sorted and non-overlapping, and thereby safe
+/// to pass to [`every_range`]. Reversed ranges, i.e. where `start > end`,
+/// are discarded.
+///
+/// *See [`merge_overlapping`] to keep adjacent ranges separate.*
+///
+/// [`every_range`]: trait.EveryRange.html#method.every_range
+/// [`merge_overlapping`]: fn.merge_overlapping.html
+///
+/// # Example
+///
+/// ```
+/// use every_range::merge_adjacent;
+///
+/// let ranges = merge_adjacent(vec![6..8, 0..3, 2..4, 4..5]);
+/// assert_eq!(ranges, [0..5, 6..8]);
+/// ```
+pub fn merge_adjacent(iter: impl IntoIterator<Item = Range<usize>>) -> Vec<Range<usize>> {
+    merge_ranges(forward_ranges(iter), true)
+}
+
+/// Returns `true` if `ranges` form a contiguous partition of `0..len`,
+/// i.e. each range starts where the previous range ended, the first
+/// starts at `0` and the last ends at `len`.
+///
+/// Returns `false` if there are any gaps, or if `ranges` are not sorted
+/// and non-overlapping.
+///
+/// # Example
+///
+/// ```
+/// use every_range::covers_entirely;
+///
+/// assert!(covers_entirely(vec![0..5, 5..10], 10));
+/// assert!(!covers_entirely(vec![0..4, 5..10], 10));
+/// ```
+pub fn covers_entirely(ranges: impl IntoIterator<Item = Range<usize>>, len: usize) -> bool {
+    let mut end = 0;
+
+    for range in ranges {
+        if (range.start != end) || (range.start > range.end) {
+            return false;
+        }
+        end = range.end;
+    }
+
+    end == len
+}
+
+/// Returns the sum of the lengths of `ranges`.
+///
+/// *Note that overlapping ranges are counted multiple times,
+/// see [`merge_overlapping`] to merge them beforehand.*
+///
+/// [`merge_overlapping`]: fn.merge_overlapping.html
+///
+/// # Example
+///
+/// ```
+/// use every_range::total_covered_len;
+///
+/// assert_eq!(total_covered_len(vec![1..3, 7..9]), 4);
+/// ```
+#[inline]
+pub fn total_covered_len(ranges: impl IntoIterator<Item = Range<usize>>) -> usize {
+    ranges.into_iter().map(|range| range.len()).sum()
+}
+
+#[inline]
+fn forward_ranges(iter: impl IntoIterator<Item = Range<usize>>) -> Vec<Range<usize>> {
+    iter.into_iter()
+        .filter(|range| range.start <= range.end)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EveryRange;
+
+    /// Deterministic xorshift, generating random ranges within `0..64`.
+    fn random_ranges(seed: &mut u64, n: usize) -> Vec<Range<usize>> {
+        let mut rand = || {
+            *seed ^= *seed << 13;
+            *seed ^= *seed >> 7;
+            *seed ^= *seed << 17;
+            (*seed % 64) as usize
+        };
+
+        (0..n)
+            .map(|_| {
+                let start = rand();
+                let len = rand() % 8;
+                start..(start + len).min(64)
+            })
+            .collect()
+    }
+
+    fn union(ranges: &[Range<usize>]) -> u64 {
+        ranges
+            .iter()
+            .flat_map(|range| range.clone())
+            .fold(0, |mask, i| mask | (1 << i))
+    }
+
+    fn assert_merged(ranges: &[Range<usize>], adjacent: bool) {
+        for pair in ranges.windows(2) {
+            if adjacent {
+                assert!(pair[0].end < pair[1].start, "{:?}", pair);
+            } else {
+                assert!(pair[0].end <= pair[1].start, "{:?}", pair);
+            }
+        }
+
+        assert!(ranges.iter().cloned().every_range(64).count() >= ranges.len());
+    }
+
+    #[test]
+    fn merge_overlapping_union() {
+        let mut seed = 0x2545_F491_4F6C_DD1D;
+
+        for n in 0..200 {
+            let ranges = random_ranges(&mut seed, n % 20);
+            let merged = merge_overlapping(ranges.clone());
+
+            assert_merged(&merged, false);
+            assert_eq!(union(&ranges), union(&merged));
+        }
+    }
+
+    #[test]
+    fn merge_adjacent_union() {
+        let mut seed = 0x9E37_79B9_7F4A_7C15;
+
+        for n in 0..200 {
+            let ranges = random_ranges(&mut seed, n % 20);
+            let merged = merge_adjacent(ranges.clone());
+
+            assert_merged(&merged, true);
+            assert_eq!(union(&ranges), union(&merged));
+        }
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn merge_overlapping_reversed() {
+        let merged = merge_overlapping(vec![5..3, 5..7, 0..2, 1..2]);
+        assert_eq!(merged, [0..2, 5..7]);
+    }
+
+    #[test]
+    fn covers_entirely() {
+        assert!(super::covers_entirely(vec![0..5, 5..10], 10));
+        assert!(super::covers_entirely(vec![0..5, 5..5, 5..10], 10));
+        assert!(super::covers_entirely(Vec::new(), 0));
+
+        assert!(!super::covers_entirely(vec![0..4, 5..10], 10));
+        assert!(!super::covers_entirely(vec![5..10, 0..5], 10));
+        assert!(!super::covers_entirely(vec![0..6, 4..10], 10));
+        assert!(!super::covers_entirely(vec![0..5, 5..9], 10));
+        assert!(!super::covers_entirely(std::iter::once(1..10), 10));
+        assert!(!super::covers_entirely(Vec::new(), 10));
+
+        let text = "Foo12Bar34Baz56";
+        let ranges = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .into_ranges();
+        assert!(super::covers_entirely(ranges, text.len()));
+    }
+
+    #[test]
+    fn total_covered_len() {
+        assert_eq!(super::total_covered_len(vec![1..3, 7..9]), 4);
+        assert_eq!(super::total_covered_len(vec![0..4, 2..6]), 8);
+        assert_eq!(super::total_covered_len(Vec::new()), 0);
+    }
+}
@@ -0,0 +1,265 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `OffsetAnnotatedIter` annotates each item with its offset within
+/// the [`Included`]-only and [`Excluded`]-only subsequences.
+///
+/// This `struct` is created by the [`annotate_with_offsets`]
+/// method on [`EveryRangeIter`].
+///
+/// [`annotate_with_offsets`]: struct.EveryRangeIter.html#method.annotate_with_offsets
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`Included`]: enum.EveryRangeKind.html#variant.Included
+/// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+pub struct OffsetAnnotatedIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+    included_offset: usize,
+    excluded_offset: usize,
+}
+
+impl<I> fmt::Debug for OffsetAnnotatedIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OffsetAnnotatedIter")
+            .field("iter", &self.iter)
+            .field("included_offset", &self.included_offset)
+            .field("excluded_offset", &self.excluded_offset)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create an [`OffsetAnnotatedIter`], which yields
+    /// `(EveryRangeKind, Range<usize>, usize, usize)`.
+    ///
+    /// The first `usize` is the amount of [`Included`] bytes yielded
+    /// before this range, i.e. the offset within the [`Included`]-only
+    /// subsequence. The second `usize` is the same for [`Excluded`] bytes.
+    ///
+    /// [`OffsetAnnotatedIter`]: struct.OffsetAnnotatedIter.html
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![2..4, 5..8]
+    ///     .into_iter()
+    ///     .every_range(10)
+    ///     .annotate_with_offsets()
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     items,
+    ///     [
+    ///         (Excluded, 0..2, 0, 0),
+    ///         (Included, 2..4, 0, 2),
+    ///         (Excluded, 4..5, 2, 2),
+    ///         (Included, 5..8, 2, 3),
+    ///         (Excluded, 8..10, 5, 3),
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    pub fn annotate_with_offsets(self) -> OffsetAnnotatedIter<I> {
+        OffsetAnnotatedIter {
+            iter: self,
+            included_offset: 0,
+            excluded_offset: 0,
+        }
+    }
+}
+
+impl<I> Iterator for OffsetAnnotatedIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>, usize, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (kind, range) = self.iter.next()?;
+
+        let item = (
+            kind,
+            range.clone(),
+            self.included_offset,
+            self.excluded_offset,
+        );
+
+        match kind {
+            EveryRangeKind::Included => self.included_offset += range.len(),
+            EveryRangeKind::Excluded => self.excluded_offset += range.len(),
+        }
+
+        Some(item)
+    }
+}
+
+impl<I> FusedIterator for OffsetAnnotatedIter<I> where I: Iterator<Item = Range<usize>> {}
+
+/// `SeqIndexedIter` annotates each item with its 0-based
+/// sequential index, i.e. the order in which it is yielded.
+///
+/// This `struct` is created by the [`every_range_with_seq_index`]
+/// method on [`EveryRangeIter`].
+///
+/// [`every_range_with_seq_index`]: struct.EveryRangeIter.html#method.every_range_with_seq_index
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct SeqIndexedIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+    seq_index: usize,
+}
+
+impl<I> fmt::Debug for SeqIndexedIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SeqIndexedIter")
+            .field("iter", &self.iter)
+            .field("seq_index", &self.seq_index)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`SeqIndexedIter`], which yields
+    /// `(seq_index, EveryRangeKind, Range<usize>)`.
+    ///
+    /// The `seq_index` is the 0-based index of the item among all the
+    /// yielded items, e.g. for labeling output or generating HTML `id`s.
+    ///
+    /// *See [`annotate_with_offsets`] for indices within each kind.*
+    ///
+    /// [`SeqIndexedIter`]: struct.SeqIndexedIter.html
+    /// [`annotate_with_offsets`]: struct.EveryRangeIter.html#method.annotate_with_offsets
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![2..4]
+    ///     .into_iter()
+    ///     .every_range(6)
+    ///     .every_range_with_seq_index()
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(0, Excluded, 0..2), (1, Included, 2..4), (2, Excluded, 4..6)]);
+    /// ```
+    #[inline]
+    pub fn every_range_with_seq_index(self) -> SeqIndexedIter<I> {
+        SeqIndexedIter {
+            iter: self,
+            seq_index: 0,
+        }
+    }
+}
+
+impl<I> Iterator for SeqIndexedIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (usize, EveryRangeKind, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (kind, range) = self.iter.next()?;
+
+        let seq_index = self.seq_index;
+        self.seq_index += 1;
+
+        Some((seq_index, kind, range))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> FusedIterator for SeqIndexedIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn annotate_with_offsets() {
+        let text = "Foo rust-lang.org Bar\nBaz crates.io Qux";
+
+        let included = "rust-lang.orgcrates.io";
+        let excluded = "Foo  Bar\nBaz  Qux";
+
+        let iter = vec![4..17, 26..35]
+            .into_iter()
+            .every_range(text.len())
+            .annotate_with_offsets();
+
+        for (kind, range, included_offset, excluded_offset) in iter {
+            let len = range.len();
+            let part = &text[range];
+
+            match kind {
+                Included => assert_eq!(part, &included[included_offset..][..len]),
+                Excluded => assert_eq!(part, &excluded[excluded_offset..][..len]),
+            }
+        }
+    }
+
+    #[test]
+    fn every_range_with_seq_index() {
+        let text = "Foo12Bar34Baz56";
+
+        let ranges = || {
+            text.match_indices(char::is_numeric)
+                .map(|(start, part)| start..(start + part.len()))
+        };
+
+        let actual = ranges()
+            .every_range(text.len())
+            .every_range_with_seq_index()
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual.len(), 9);
+        for (i, (seq_index, _, _)) in actual.iter().enumerate() {
+            assert_eq!(*seq_index, i);
+        }
+
+        let items = actual
+            .into_iter()
+            .map(|(_, kind, range)| (kind, range))
+            .collect::<Vec<_>>();
+        assert_eq!(items, ranges().every_range(text.len()).collect::<Vec<_>>());
+
+        let mut iter = [0..1, 1..10, 50..51].iter().cloned().every_range(100);
+        assert_eq!(iter.next(), Some((Included, 0..1)));
+
+        let seq_indices = iter
+            .every_range_with_seq_index()
+            .map(|(seq_index, _, _)| seq_index)
+            .collect::<Vec<_>>();
+        assert_eq!(seq_indices, [0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,181 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+use std::vec;
+
+use crate::merge::merge_ranges;
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `OverlapGroupIter` merges overlapping input ranges into groups.
+///
+/// This `struct` is created by the [`into_overlap_groups`]
+/// method on [`EveryRangeIter`].
+///
+/// [`into_overlap_groups`]: struct.EveryRangeIter.html#method.into_overlap_groups
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct OverlapGroupIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: Option<EveryRangeIter<I>>,
+    groups: Option<EveryRangeIter<vec::IntoIter<Range<usize>>>>,
+}
+
+impl<I> fmt::Debug for OverlapGroupIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OverlapGroupIter")
+            .field("iter", &self.iter)
+            .field("groups", &self.groups)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create an [`OverlapGroupIter`], which accepts overlapping and
+    /// unordered input ranges, instead of panicking.
+    ///
+    /// All ranges that form a connected component, i.e. where two ranges
+    /// are connected if they overlap, are merged into a single [`Included`]
+    /// range. The uncovered regions are yielded as [`Excluded`].
+    ///
+    /// *Note that all input ranges are collected and sorted,
+    /// upon the first call to `next`.*
+    ///
+    /// [`OverlapGroupIter`]: struct.OverlapGroupIter.html
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Panics
+    ///
+    /// Panics if any range exceeds the `end` of the `EveryRangeIter`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![4..8, 1..3, 2..5]
+    ///     .into_iter()
+    ///     .every_range(10)
+    ///     .into_overlap_groups()
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Excluded, 0..1), (Included, 1..8), (Excluded, 8..10)]);
+    /// ```
+    #[inline]
+    pub fn into_overlap_groups(self) -> OverlapGroupIter<I> {
+        OverlapGroupIter {
+            iter: Some(self),
+            groups: None,
+        }
+    }
+}
+
+impl<I> Iterator for OverlapGroupIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(iter) = self.iter.take() {
+            self.groups =
+                Some(iter.rebuild(|ranges| merge_ranges(ranges.collect(), false).into_iter()));
+        }
+
+        self.groups.as_mut()?.next()
+    }
+}
+
+impl<I> FusedIterator for OverlapGroupIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn into_overlap_groups() {
+        let actual = [8..10, 0..2, 4..6, 5..7, 1..3, 12..12, 10..11]
+            .iter()
+            .cloned()
+            .every_range(15)
+            .into_overlap_groups()
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Included, 0..3),
+                (Excluded, 3..4),
+                (Included, 4..7),
+                (Excluded, 7..8),
+                (Included, 8..10),
+                (Included, 10..11),
+                (Excluded, 11..12),
+                (Included, 12..12),
+                (Excluded, 12..15),
+            ]
+        );
+    }
+
+    #[test]
+    fn into_overlap_groups_partially_consumed() {
+        let mut iter = [2..4, 8..10, 3..6].iter().cloned().every_range(10);
+
+        assert_eq!(iter.next(), Some((Excluded, 0..2)));
+
+        let actual = iter.into_overlap_groups().collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            [(Included, 2..6), (Excluded, 6..8), (Included, 8..10)]
+        );
+    }
+
+    #[test]
+    #[should_panic = "assertion failed: next.end <= self.end"]
+    fn into_overlap_groups_exceeds_end() {
+        [0..2, 4..8]
+            .iter()
+            .cloned()
+            .every_range(5)
+            .into_overlap_groups()
+            .for_each(|_| {});
+    }
+
+    #[test]
+    fn into_overlap_groups_skip_trailing() {
+        let actual = vec![4..8, 1..3, 2..5]
+            .into_iter()
+            .every_range(10)
+            .skip_trailing()
+            .into_overlap_groups()
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, [(Excluded, 0..1), (Included, 1..8)]);
+    }
+
+    #[test]
+    fn into_overlap_groups_consumed_from_back() {
+        let mut iter = vec![2..4, 6..8].into_iter().every_range(10);
+        assert_eq!(iter.next_back(), Some((Excluded, 8..10)));
+
+        let actual = iter.into_overlap_groups().collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            [
+                (Excluded, 0..2),
+                (Included, 2..4),
+                (Excluded, 4..6),
+                (Included, 6..8)
+            ]
+        );
+    }
+}
@@ -0,0 +1,330 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+use std::str::{CharIndices, MatchIndices};
+
+use crate::{DebugPlaceholder, EveryRangeIter};
+
+/// `StrMatchRanges` yields the byte ranges of all non-overlapping
+/// occurrences of a pattern in a text.
+///
+/// This `struct` is used by the [`EveryRangeIter`] created by the
+/// [`every_range_from_str_indices`] function on [`EveryRangeIter`].
+///
+/// [`every_range_from_str_indices`]: struct.EveryRangeIter.html#method.every_range_from_str_indices
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct StrMatchRanges<'a> {
+    iter: MatchIndices<'a, &'a str>,
+}
+
+impl<'a> fmt::Debug for StrMatchRanges<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StrMatchRanges")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<'a> Iterator for StrMatchRanges<'a> {
+    type Item = Range<usize>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, part) = self.iter.next()?;
+        Some(start..(start + part.len()))
+    }
+}
+
+impl<'a> FusedIterator for StrMatchRanges<'a> {}
+
+impl<'a> EveryRangeIter<StrMatchRanges<'a>> {
+    /// Create an [`EveryRangeIter`] over `text`, where the [`Included`]
+    /// ranges are all non-overlapping occurrences of `pattern`, as found
+    /// by [`str::match_indices`].
+    ///
+    /// [`EveryRangeIter`]: struct.EveryRangeIter.html
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`str::match_indices`]: https://doc.rust-lang.org/stable/std/primitive.str.html#method.match_indices
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRangeIter, EveryRangeKind::*};
+    ///
+    /// let text = "Foo Bar Foo";
+    ///
+    /// let items = EveryRangeIter::every_range_from_str_indices(text, "Foo")
+    ///     .map(|(kind, range)| (kind, &text[range]))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Included, "Foo"), (Excluded, " Bar "), (Included, "Foo")]);
+    /// ```
+    #[inline]
+    pub fn every_range_from_str_indices(text: &'a str, pattern: &'a str) -> Self {
+        let ranges = StrMatchRanges {
+            iter: text.match_indices(pattern),
+        };
+
+        EveryRangeIter::new(ranges, text.len())
+    }
+
+    /// Create an [`EveryRangeIter`] over `text`, where the [`Included`]
+    /// ranges are the occurrences of `delimiter`, and the [`Excluded`]
+    /// ranges are the parts between them, as would be yielded by
+    /// [`str::split`].
+    ///
+    /// This is the same as [`every_range_from_str_indices`],
+    /// i.e. it highlights the delimiters instead of the parts.
+    ///
+    /// *Note that unlike [`str::split`], empty parts are not yielded,
+    /// e.g. between two consecutive delimiters.*
+    ///
+    /// [`EveryRangeIter`]: struct.EveryRangeIter.html
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    /// [`every_range_from_str_indices`]: struct.EveryRangeIter.html#method.every_range_from_str_indices
+    /// [`str::split`]: https://doc.rust-lang.org/stable/std/primitive.str.html#method.split
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRangeIter, EveryRangeKind::*};
+    ///
+    /// let text = "Foo, Bar, Baz";
+    ///
+    /// let items = EveryRangeIter::every_range_from_split(text, ", ")
+    ///     .map(|(kind, range)| (kind, &text[range]))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     items,
+    ///     [
+    ///         (Excluded, "Foo"),
+    ///         (Included, ", "),
+    ///         (Excluded, "Bar"),
+    ///         (Included, ", "),
+    ///         (Excluded, "Baz"),
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    pub fn every_range_from_split(text: &'a str, delimiter: &'a str) -> Self {
+        Self::every_range_from_str_indices(text, delimiter)
+    }
+}
+
+/// `CharRunRanges` yields the byte ranges of all runs of consecutive
+/// characters matching a predicate in a text.
+///
+/// This `struct` is used by the [`EveryRangeIter`] created by the
+/// [`every_range_from_char_indices`] function on [`EveryRangeIter`].
+///
+/// [`every_range_from_char_indices`]: struct.EveryRangeIter.html#method.every_range_from_char_indices
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct CharRunRanges<'a, F>
+where
+    F: Fn(char) -> bool,
+{
+    iter: CharIndices<'a>,
+    predicate: F,
+    len: usize,
+}
+
+impl<'a, F> fmt::Debug for CharRunRanges<'a, F>
+where
+    F: Fn(char) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CharRunRanges")
+            .field("iter", &self.iter)
+            .field("predicate", &DebugPlaceholder("<closure>"))
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<'a, F> Iterator for CharRunRanges<'a, F>
+where
+    F: Fn(char) -> bool,
+{
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let predicate = &self.predicate;
+
+        let start = loop {
+            let (i, c) = self.iter.next()?;
+            if predicate(c) {
+                break i;
+            }
+        };
+
+        let end = loop {
+            match self.iter.next() {
+                Some((_, c)) if predicate(c) => {}
+                Some((i, _)) => break i,
+                None => break self.len,
+            }
+        };
+
+        Some(start..end)
+    }
+}
+
+impl<'a, F> FusedIterator for CharRunRanges<'a, F> where F: Fn(char) -> bool {}
+
+impl<'a, F> EveryRangeIter<CharRunRanges<'a, F>>
+where
+    F: Fn(char) -> bool,
+{
+    /// Create an [`EveryRangeIter`] over `text`, where the [`Included`]
+    /// ranges are all runs of consecutive characters matching `predicate`.
+    ///
+    /// [`EveryRangeIter`]: struct.EveryRangeIter.html
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRangeIter, EveryRangeKind::*};
+    ///
+    /// let text = "Foo12Bar345";
+    ///
+    /// let items = EveryRangeIter::every_range_from_char_indices(text, char::is_numeric)
+    ///     .map(|(kind, range)| (kind, &text[range]))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     items,
+    ///     [(Excluded, "Foo"), (Included, "12"), (Excluded, "Bar"), (Included, "345")]
+    /// );
+    /// ```
+    #[inline]
+    pub fn every_range_from_char_indices(text: &'a str, predicate: F) -> Self {
+        let ranges = CharRunRanges {
+            iter: text.char_indices(),
+            predicate,
+            len: text.len(),
+        };
+
+        EveryRangeIter::new(ranges, text.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn every_range_from_str_indices() {
+        let text = "Foo12Bar12Baz1212";
+
+        let actual = EveryRangeIter::every_range_from_str_indices(text, "12")
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Excluded, "Foo"),
+                (Included, "12"),
+                (Excluded, "Bar"),
+                (Included, "12"),
+                (Excluded, "Baz"),
+                (Included, "12"),
+                (Included, "12"),
+            ]
+        );
+    }
+
+    #[test]
+    fn every_range_from_str_indices_non_overlapping() {
+        let text = "aaaaa";
+
+        let actual = EveryRangeIter::every_range_from_str_indices(text, "aa").collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [(Included, 0..2), (Included, 2..4), (Excluded, 4..5)]
+        );
+    }
+
+    #[test]
+    fn every_range_from_str_indices_no_match() {
+        let actual = EveryRangeIter::every_range_from_str_indices("Foo", "Bar").collect::<Vec<_>>();
+
+        assert_eq!(actual, [(Excluded, 0..3)]);
+    }
+
+    #[test]
+    fn every_range_from_split() {
+        let text = ",Foo,,Bar,";
+
+        let excluded = EveryRangeIter::every_range_from_split(text, ",")
+            .filter(|&(kind, _)| kind == Excluded)
+            .map(|(_, range)| &text[range])
+            .collect::<Vec<_>>();
+
+        let parts = text
+            .split(',')
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>();
+
+        assert_eq!(excluded, parts);
+
+        let included = EveryRangeIter::every_range_from_split(text, ",")
+            .filter(|&(kind, _)| kind == Included)
+            .count();
+        assert_eq!(included, 4);
+    }
+
+    #[test]
+    fn every_range_from_char_indices() {
+        let text = "Foo12Bar34Baz56";
+
+        let actual = EveryRangeIter::every_range_from_char_indices(text, char::is_numeric)
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Excluded, "Foo"),
+                (Included, "12"),
+                (Excluded, "Bar"),
+                (Included, "34"),
+                (Excluded, "Baz"),
+                (Included, "56"),
+            ]
+        );
+    }
+
+    #[test]
+    fn every_range_from_char_indices_multibyte() {
+        let text = "Føø  Bår";
+
+        let actual = EveryRangeIter::every_range_from_char_indices(text, |c| !c.is_ascii())
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Excluded, "F"),
+                (Included, "øø"),
+                (Excluded, "  B"),
+                (Included, "å"),
+                (Excluded, "r"),
+            ]
+        );
+    }
+
+    #[test]
+    fn every_range_from_char_indices_no_match() {
+        let actual = EveryRangeIter::every_range_from_char_indices("Foo", char::is_whitespace)
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, [(Excluded, 0..3)]);
+    }
+}
@@ -0,0 +1,103 @@
+use std::ops::Range;
+
+use crate::EveryRangeIter;
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Peek at the next [`Included`] range, without advancing past the
+    /// [`Excluded`] range preceding it.
+    ///
+    /// Returns the next [`Included`] range (if any) along with the
+    /// `EveryRangeIter`, which when iterated yields the same sequence
+    /// as if `peek_next_included` was never called.
+    ///
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let iter = vec![2..4].into_iter().every_range(6);
+    ///
+    /// let (next, mut iter) = iter.peek_next_included();
+    /// assert_eq!(next, Some(2..4));
+    ///
+    /// assert_eq!(iter.next(), Some((Excluded, 0..2)));
+    /// assert_eq!(iter.next(), Some((Included, 2..4)));
+    /// assert_eq!(iter.next(), Some((Excluded, 4..6)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn peek_next_included(mut self) -> (Option<Range<usize>>, Self) {
+        if self.next.is_none() {
+            self.next = self.iter.next().or_else(|| self.back.take());
+        }
+
+        (self.next.clone(), self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn peek_next_included() {
+        let ranges = [3..5, 5..10, 13..15];
+        let expected = ranges.iter().cloned().every_range(20).collect::<Vec<_>>();
+
+        let mut iter = ranges.iter().cloned().every_range(20);
+        let mut actual = Vec::new();
+
+        loop {
+            let (next, peeked) = iter.peek_next_included();
+            let (peeked_again, peeked) = peeked.peek_next_included();
+            assert_eq!(next, peeked_again);
+
+            iter = peeked;
+
+            match iter.next() {
+                Some(item) => {
+                    if item.0 == Included {
+                        assert_eq!(Some(item.1.clone()), next);
+                    }
+                    actual.push(item);
+                }
+                None => break,
+            }
+        }
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn peek_next_included_none() {
+        let iter = std::iter::once(0..5).every_range(8);
+        let (_, mut iter) = iter.peek_next_included();
+
+        assert_eq!(iter.next(), Some((Included, 0..5)));
+
+        let (next, mut iter) = iter.peek_next_included();
+        assert_eq!(next, None);
+        assert_eq!(iter.next(), Some((Excluded, 5..8)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn peek_next_included_consumed_from_back() {
+        let mut iter = std::iter::once(2..4).every_range(6);
+        assert_eq!(iter.next_back(), Some((Excluded, 4..6)));
+
+        let (next, iter) = iter.peek_next_included();
+        assert_eq!(next, Some(2..4));
+        assert_eq!(
+            iter.collect::<Vec<_>>(),
+            [(Excluded, 0..2), (Included, 2..4)]
+        );
+    }
+}
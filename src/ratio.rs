@@ -0,0 +1,114 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `RatioIter` annotates each item with how far through `0..end` it starts.
+///
+/// This `struct` is created by the [`into_ratio_iter`]
+/// method on [`EveryRangeIter`].
+///
+/// [`into_ratio_iter`]: struct.EveryRangeIter.html#method.into_ratio_iter
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct RatioIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+}
+
+impl<I> fmt::Debug for RatioIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RatioIter")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`RatioIter`], which yields `(EveryRangeKind, Range<usize>, f64)`,
+    /// where the `f64` is the ratio of `range.start` to `end`, i.e. a value
+    /// in `[0.0, 1.0]`. If `end` is `0`, then the ratio is `0.0`.
+    ///
+    /// [`RatioIter`]: struct.RatioIter.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![2..6].into_iter().every_range(8).into_ratio_iter().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Excluded, 0..2, 0.0), (Included, 2..6, 0.25), (Excluded, 6..8, 0.75)]);
+    /// ```
+    #[inline]
+    pub fn into_ratio_iter(self) -> RatioIter<I> {
+        RatioIter { iter: self }
+    }
+}
+
+impl<I> Iterator for RatioIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>, f64);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (kind, range) = self.iter.next()?;
+
+        let end = self.iter.end;
+        let ratio = if end > 0 {
+            range.start as f64 / end as f64
+        } else {
+            0.0
+        };
+
+        Some((kind, range, ratio))
+    }
+}
+
+impl<I> FusedIterator for RatioIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn into_ratio_iter() {
+        let text = "Foo12Bar34Baz56";
+
+        let ratios = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .into_ratio_iter()
+            .map(|(_, _, ratio)| ratio)
+            .collect::<Vec<_>>();
+
+        assert_eq!(ratios.len(), 9);
+        assert_eq!(ratios[0], 0.0);
+        assert!(ratios.windows(2).all(|w| w[0] < w[1]));
+        assert!(ratios.iter().all(|&ratio| (0.0..1.0).contains(&ratio)));
+    }
+
+    #[test]
+    fn into_ratio_iter_empty_end() {
+        let items = [0..0, 0..0]
+            .iter()
+            .cloned()
+            .every_range(0)
+            .into_ratio_iter()
+            .collect::<Vec<_>>();
+
+        assert_eq!(items, [(Included, 0..0, 0.0), (Included, 0..0, 0.0)]);
+    }
+}
@@ -0,0 +1,169 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+use std::vec;
+
+use crate::{DebugPlaceholder, EveryRangeIter, EveryRangeKind};
+
+/// `ReorderedIter` yields the items of an [`EveryRangeIter`]
+/// sorted by a comparator.
+///
+/// This `struct` is created by the [`reorder_by`]
+/// method on [`EveryRangeIter`].
+///
+/// [`reorder_by`]: struct.EveryRangeIter.html#method.reorder_by
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct ReorderedIter<I, F>
+where
+    I: Iterator<Item = Range<usize>>,
+    F: FnMut(&(EveryRangeKind, Range<usize>), &(EveryRangeKind, Range<usize>)) -> Ordering,
+{
+    iter: Option<EveryRangeIter<I>>,
+    comparator: F,
+    items: vec::IntoIter<(EveryRangeKind, Range<usize>)>,
+}
+
+impl<I, F> fmt::Debug for ReorderedIter<I, F>
+where
+    I: Iterator<Item = Range<usize>>,
+    F: FnMut(&(EveryRangeKind, Range<usize>), &(EveryRangeKind, Range<usize>)) -> Ordering,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReorderedIter")
+            .field("iter", &self.iter)
+            .field("comparator", &DebugPlaceholder("<closure>"))
+            .field("items", &self.items)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`ReorderedIter`], which yields all items sorted
+    /// by `comparator`. The sort is stable, i.e. items comparing
+    /// equal retain their original order.
+    ///
+    /// This is intended for presentation, e.g. displaying matches sorted
+    /// by length. *Note that the reordered ranges generally no longer
+    /// form a contiguous covering of `0..end`.*
+    ///
+    /// *Note that all items are collected into a [`Vec`],
+    /// upon the first call to `next`.*
+    ///
+    /// [`ReorderedIter`]: struct.ReorderedIter.html
+    /// [`Vec`]: https://doc.rust-lang.org/stable/std/vec/struct.Vec.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![1..2, 4..7]
+    ///     .into_iter()
+    ///     .every_range(8)
+    ///     .reorder_by(|(_, a), (_, b)| b.len().cmp(&a.len()))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     items,
+    ///     [
+    ///         (Included, 4..7),
+    ///         (Excluded, 2..4),
+    ///         (Excluded, 0..1),
+    ///         (Included, 1..2),
+    ///         (Excluded, 7..8),
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    pub fn reorder_by<F>(self, comparator: F) -> ReorderedIter<I, F>
+    where
+        F: FnMut(&(EveryRangeKind, Range<usize>), &(EveryRangeKind, Range<usize>)) -> Ordering,
+    {
+        ReorderedIter {
+            iter: Some(self),
+            comparator,
+            items: Vec::new().into_iter(),
+        }
+    }
+}
+
+impl<I, F> Iterator for ReorderedIter<I, F>
+where
+    I: Iterator<Item = Range<usize>>,
+    F: FnMut(&(EveryRangeKind, Range<usize>), &(EveryRangeKind, Range<usize>)) -> Ordering,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(iter) = self.iter.take() {
+            let mut items = iter.collect::<Vec<_>>();
+            items.sort_by(&mut self.comparator);
+            self.items = items.into_iter();
+        }
+
+        self.items.next()
+    }
+}
+
+impl<I, F> FusedIterator for ReorderedIter<I, F>
+where
+    I: Iterator<Item = Range<usize>>,
+    F: FnMut(&(EveryRangeKind, Range<usize>), &(EveryRangeKind, Range<usize>)) -> Ordering,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn reorder_by() {
+        let text = "Foo12Bar34Baz56";
+
+        let actual = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .reorder_by(|(a, _), (b, _)| (*a == Excluded).cmp(&(*b == Excluded)))
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Included, "1"),
+                (Included, "2"),
+                (Included, "3"),
+                (Included, "4"),
+                (Included, "5"),
+                (Included, "6"),
+                (Excluded, "Foo"),
+                (Excluded, "Bar"),
+                (Excluded, "Baz"),
+            ]
+        );
+    }
+
+    #[test]
+    fn reorder_by_reverse() {
+        let ranges = [2..4, 6..8];
+
+        let mut expected = ranges.iter().cloned().every_range(10).collect::<Vec<_>>();
+        expected.reverse();
+
+        let actual = ranges
+            .iter()
+            .cloned()
+            .every_range(10)
+            .reorder_by(|(_, a), (_, b)| b.start.cmp(&a.start))
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+}
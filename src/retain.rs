@@ -0,0 +1,160 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `RetainKindIter` only yields ranges of a single kind, which are
+/// expanded to absorb the dropped ranges.
+///
+/// This `struct` is created by the [`retain_kind`]
+/// method on [`EveryRangeIter`].
+///
+/// [`retain_kind`]: struct.EveryRangeIter.html#method.retain_kind
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct RetainKindIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+    kind: EveryRangeKind,
+    pending: Option<Range<usize>>,
+    /// The start of the dropped ranges preceding the first retained range.
+    leading: Option<usize>,
+}
+
+impl<I> fmt::Debug for RetainKindIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetainKindIter")
+            .field("iter", &self.iter)
+            .field("kind", &self.kind)
+            .field("pending", &self.pending)
+            .field("leading", &self.leading)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`RetainKindIter`], which only yields ranges of `kind`,
+    /// while maintaining complete coverage.
+    ///
+    /// Every dropped range is absorbed into the preceding retained range,
+    /// or if there is none, then into the following retained range.
+    /// Thereby the retained ranges span `0..end` contiguously. If there
+    /// are no ranges of `kind`, then nothing is yielded.
+    ///
+    /// [`RetainKindIter`]: struct.RetainKindIter.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![2..4, 6..8]
+    ///     .into_iter()
+    ///     .every_range(10)
+    ///     .retain_kind(Included)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Included, 0..6), (Included, 6..10)]);
+    /// ```
+    #[inline]
+    pub fn retain_kind(self, kind: EveryRangeKind) -> RetainKindIter<I> {
+        RetainKindIter {
+            iter: self,
+            kind,
+            pending: None,
+            leading: None,
+        }
+    }
+}
+
+impl<I> Iterator for RetainKindIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next() {
+                Some((kind, range)) if kind == self.kind => {
+                    let range = match self.leading.take() {
+                        Some(start) => start..range.end,
+                        None => range,
+                    };
+
+                    if let Some(prev) = self.pending.replace(range) {
+                        return Some((self.kind, prev));
+                    }
+                }
+                Some((_, range)) => match &mut self.pending {
+                    Some(pending) => pending.end = range.end,
+                    None => {
+                        self.leading.get_or_insert(range.start);
+                    }
+                },
+                None => return self.pending.take().map(|range| (self.kind, range)),
+            }
+        }
+    }
+}
+
+impl<I> FusedIterator for RetainKindIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn retain_kind() {
+        let text = "Foo12Bar34Baz56";
+
+        let retain = |kind| {
+            text.match_indices(char::is_numeric)
+                .map(|(start, part)| start..(start + part.len()))
+                .every_range(text.len())
+                .retain_kind(kind)
+                .map(|(kind, range)| (kind, &text[range]))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            retain(Included),
+            [
+                (Included, "Foo1"),
+                (Included, "2Bar"),
+                (Included, "3"),
+                (Included, "4Baz"),
+                (Included, "5"),
+                (Included, "6"),
+            ]
+        );
+        assert_eq!(
+            retain(Excluded),
+            [
+                (Excluded, "Foo12"),
+                (Excluded, "Bar34"),
+                (Excluded, "Baz56"),
+            ]
+        );
+    }
+
+    #[test]
+    fn retain_kind_none() {
+        let count = Vec::new()
+            .into_iter()
+            .every_range(10)
+            .retain_kind(Included)
+            .count();
+
+        assert_eq!(count, 0);
+    }
+}
@@ -0,0 +1,123 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `RevEveryRangeIter` yields the items of an [`EveryRangeIter`]
+/// in reverse order.
+///
+/// This `struct` is created by the [`emit_in_reverse`]
+/// method on [`EveryRangeIter`].
+///
+/// [`emit_in_reverse`]: struct.EveryRangeIter.html#method.emit_in_reverse
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct RevEveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: Option<EveryRangeIter<I>>,
+    items: Vec<(EveryRangeKind, Range<usize>)>,
+}
+
+impl<I> fmt::Debug for RevEveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RevEveryRangeIter")
+            .field("iter", &self.iter)
+            .field("items", &self.items)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`RevEveryRangeIter`], which yields all items
+    /// in reverse order, i.e. the last item is yielded first.
+    ///
+    /// Only the order of the items is reversed, the ranges
+    /// themselves and their kinds are unchanged.
+    ///
+    /// *Note that all items are collected into a [`Vec`],
+    /// upon the first call to `next`.*
+    ///
+    /// [`RevEveryRangeIter`]: struct.RevEveryRangeIter.html
+    /// [`Vec`]: https://doc.rust-lang.org/stable/std/vec/struct.Vec.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![2..4]
+    ///     .into_iter()
+    ///     .every_range(6)
+    ///     .emit_in_reverse()
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Excluded, 4..6), (Included, 2..4), (Excluded, 0..2)]);
+    /// ```
+    #[inline]
+    pub fn emit_in_reverse(self) -> RevEveryRangeIter<I> {
+        RevEveryRangeIter {
+            iter: Some(self),
+            items: Vec::new(),
+        }
+    }
+}
+
+impl<I> Iterator for RevEveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(iter) = self.iter.take() {
+            self.items = iter.collect();
+        }
+
+        self.items.pop()
+    }
+}
+
+impl<I> FusedIterator for RevEveryRangeIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+
+    #[test]
+    fn emit_in_reverse() {
+        let text = "Foo12Bar34Baz56";
+
+        let mut expected = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .collect::<Vec<_>>();
+        expected.reverse();
+
+        let actual = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .emit_in_reverse()
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn emit_in_reverse_empty() {
+        let mut iter = Vec::new().into_iter().every_range(0).emit_in_reverse();
+
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+}
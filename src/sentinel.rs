@@ -0,0 +1,127 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `WithEndSentinelIter` emits an additional zero-length
+/// [`Excluded`] range at the end, after the inner
+/// [`EveryRangeIter`] is exhausted.
+///
+/// This `struct` is created by the [`with_end_sentinel`]
+/// method on [`EveryRangeIter`].
+///
+/// [`with_end_sentinel`]: struct.EveryRangeIter.html#method.with_end_sentinel
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+pub struct WithEndSentinelIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+    sentinel: bool,
+}
+
+impl<I> fmt::Debug for WithEndSentinelIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WithEndSentinelIter")
+            .field("iter", &self.iter)
+            .field("sentinel", &self.sentinel)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`WithEndSentinelIter`], which after the last range
+    /// emits one final `(Excluded, end..end)` before returning `None`.
+    ///
+    /// This allows consumers to always see a terminal item, e.g. to
+    /// flush state, without special-casing the end of the iterator.
+    ///
+    /// [`WithEndSentinelIter`]: struct.WithEndSentinelIter.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let mut iter = vec![2..4].into_iter().every_range(4).with_end_sentinel();
+    ///
+    /// assert_eq!(iter.next(), Some((Excluded, 0..2)));
+    /// assert_eq!(iter.next(), Some((Included, 2..4)));
+    /// assert_eq!(iter.next(), Some((Excluded, 4..4)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn with_end_sentinel(self) -> WithEndSentinelIter<I> {
+        WithEndSentinelIter {
+            iter: self,
+            sentinel: true,
+        }
+    }
+}
+
+impl<I> Iterator for WithEndSentinelIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.sentinel {
+            None
+        } else if let Some(item) = self.iter.next() {
+            Some(item)
+        } else {
+            self.sentinel = false;
+
+            let end = self.iter.end;
+            Some((EveryRangeKind::Excluded, end..end))
+        }
+    }
+}
+
+impl<I> FusedIterator for WithEndSentinelIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn with_end_sentinel() {
+        let actual = vec![3..5, 8..10]
+            .into_iter()
+            .every_range(12)
+            .with_end_sentinel()
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Excluded, 0..3),
+                (Included, 3..5),
+                (Excluded, 5..8),
+                (Included, 8..10),
+                (Excluded, 10..12),
+                (Excluded, 12..12),
+            ]
+        );
+    }
+
+    #[test]
+    fn with_end_sentinel_empty() {
+        let mut iter = Vec::new().into_iter().every_range(0).with_end_sentinel();
+
+        assert_eq!(iter.next(), Some((Excluded, 0..0)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,201 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `SkipFirstNExcludedIter` absorbs the first `n` [`Excluded`] ranges
+/// into the adjacent [`Included`] ranges.
+///
+/// This `struct` is created by the [`skip_first_n_excluded`]
+/// method on [`EveryRangeIter`].
+///
+/// [`skip_first_n_excluded`]: struct.EveryRangeIter.html#method.skip_first_n_excluded
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`Included`]: enum.EveryRangeKind.html#variant.Included
+/// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+pub struct SkipFirstNExcludedIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+    n: usize,
+    pending: Option<(EveryRangeKind, Range<usize>)>,
+    /// If `true`, then the next item is merged into `pending`.
+    glue: bool,
+}
+
+impl<I> fmt::Debug for SkipFirstNExcludedIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SkipFirstNExcludedIter")
+            .field("iter", &self.iter)
+            .field("n", &self.n)
+            .field("pending", &self.pending)
+            .field("glue", &self.glue)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`SkipFirstNExcludedIter`], which skips the first `n`
+    /// [`Excluded`] ranges, by absorbing them into the adjacent
+    /// [`Included`] ranges.
+    ///
+    /// An [`Excluded`] range between two [`Included`] ranges,
+    /// results in the three ranges being merged into a single
+    /// [`Included`] range. If there are no [`Included`] ranges at all,
+    /// then the [`Excluded`] range is yielded as is.
+    ///
+    /// [`SkipFirstNExcludedIter`]: struct.SkipFirstNExcludedIter.html
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![2..4, 6..8]
+    ///     .into_iter()
+    ///     .every_range(10)
+    ///     .skip_first_n_excluded(2)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Included, 0..8), (Excluded, 8..10)]);
+    /// ```
+    #[inline]
+    pub fn skip_first_n_excluded(self, n: usize) -> SkipFirstNExcludedIter<I> {
+        SkipFirstNExcludedIter {
+            iter: self,
+            n,
+            pending: None,
+            glue: false,
+        }
+    }
+}
+
+impl<I> Iterator for SkipFirstNExcludedIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next() {
+                Some((EveryRangeKind::Excluded, range)) if self.n > 0 => {
+                    self.n -= 1;
+
+                    match &mut self.pending {
+                        Some((_, pending)) => pending.end = range.end,
+                        None => self.pending = Some((EveryRangeKind::Excluded, range)),
+                    }
+
+                    self.glue = true;
+                }
+                Some((kind, range)) => {
+                    if self.glue {
+                        self.glue = false;
+
+                        if let Some((pending_kind, pending)) = &mut self.pending {
+                            pending.end = range.end;
+                            if kind == EveryRangeKind::Included {
+                                *pending_kind = EveryRangeKind::Included;
+                            }
+                            continue;
+                        }
+                    }
+
+                    if let Some(item) = self.pending.replace((kind, range)) {
+                        return Some(item);
+                    }
+                }
+                None => return self.pending.take(),
+            }
+        }
+    }
+}
+
+impl<I> FusedIterator for SkipFirstNExcludedIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn skip_first_n_excluded() {
+        let text = "Foo12Bar34Baz56";
+
+        let skip = |n| {
+            text.match_indices(char::is_alphabetic)
+                .map(|(start, part)| start..(start + part.len()))
+                .every_range(text.len())
+                .skip_first_n_excluded(n)
+                .map(|(kind, range)| (kind, &text[range]))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(skip(0).len(), 12);
+        assert_eq!(
+            skip(1),
+            [
+                (Included, "F"),
+                (Included, "o"),
+                (Included, "o12B"),
+                (Included, "a"),
+                (Included, "r"),
+                (Excluded, "34"),
+                (Included, "B"),
+                (Included, "a"),
+                (Included, "z"),
+                (Excluded, "56"),
+            ]
+        );
+        assert_eq!(
+            skip(3),
+            [
+                (Included, "F"),
+                (Included, "o"),
+                (Included, "o12B"),
+                (Included, "a"),
+                (Included, "r34B"),
+                (Included, "a"),
+                (Included, "z56"),
+            ]
+        );
+        assert_eq!(skip(3), skip(100));
+    }
+
+    #[test]
+    fn skip_first_n_excluded_leading() {
+        let actual = [2..4, 6..8]
+            .iter()
+            .cloned()
+            .every_range(8)
+            .skip_first_n_excluded(1)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [(Included, 0..4), (Excluded, 4..6), (Included, 6..8)]
+        );
+    }
+
+    #[test]
+    fn skip_first_n_excluded_without_included() {
+        let actual = Vec::new()
+            .into_iter()
+            .every_range(8)
+            .skip_first_n_excluded(1)
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, [(Excluded, 0..8)]);
+    }
+}
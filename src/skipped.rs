@@ -0,0 +1,327 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+use std::rc::Rc;
+
+use crate::fallible::check_range;
+use crate::{EveryRangeError, EveryRangeIter, EveryRangeKind, RemainingRanges};
+
+/// `SkipReport` holds all the ranges skipped by a [`SkipReportingIter`].
+///
+/// The `SkipReport` is populated as ranges are skipped while iterating
+/// the [`SkipReportingIter`], i.e. it is complete after iteration.
+///
+/// This `struct` is created by the [`report_skipped`]
+/// method on [`EveryRangeIter`].
+///
+/// [`report_skipped`]: struct.EveryRangeIter.html#method.report_skipped
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`SkipReportingIter`]: struct.SkipReportingIter.html
+#[derive(Clone, Default, Debug)]
+pub struct SkipReport(Rc<RefCell<Skipped>>);
+
+type Skipped = Vec<(Range<usize>, EveryRangeError)>;
+
+impl SkipReport {
+    /// Returns the ranges skipped so far, along with the
+    /// [`EveryRangeError`] describing why they were skipped.
+    ///
+    /// [`EveryRangeError`]: enum.EveryRangeError.html
+    #[inline]
+    pub fn skipped(&self) -> Vec<(Range<usize>, EveryRangeError)> {
+        self.0.borrow().clone()
+    }
+
+    /// Returns the amount of ranges skipped so far.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.borrow().len()
+    }
+
+    /// Returns `true` if no ranges have been skipped so far.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.borrow().is_empty()
+    }
+
+    #[inline]
+    fn push(&self, range: Range<usize>, err: EveryRangeError) {
+        self.0.borrow_mut().push((range, err));
+    }
+}
+
+/// Skips the ranges that would otherwise cause `EveryRangeIter` to panic.
+struct ReportedRanges<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: RemainingRanges<I>,
+    prev_end: usize,
+    end: usize,
+    report: SkipReport,
+}
+
+impl<I> Iterator for ReportedRanges<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for range in &mut self.iter {
+            match check_range(self.prev_end, self.end, &range) {
+                Ok(()) => {
+                    self.prev_end = range.end;
+                    return Some(range);
+                }
+                Err(err) => self.report.push(range, err),
+            }
+        }
+
+        None
+    }
+}
+
+/// `SkipReportingIter` skips invalid ranges instead of panicking,
+/// and records them in a [`SkipReport`].
+///
+/// This `struct` is created by the [`report_skipped`]
+/// method on [`EveryRangeIter`].
+///
+/// [`report_skipped`]: struct.EveryRangeIter.html#method.report_skipped
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`SkipReport`]: struct.SkipReport.html
+pub struct SkipReportingIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<ReportedRanges<I>>,
+}
+
+impl<I> fmt::Debug for SkipReportingIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SkipReportingIter")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`SkipReportingIter`], which instead of panicking skips
+    /// ranges that are out of order, overlapping, or exceeding `end`.
+    ///
+    /// Every skipped range is recorded in the returned [`SkipReport`]
+    /// along with an [`EveryRangeError`], as the iterator is being iterated.
+    ///
+    /// [`SkipReportingIter`]: struct.SkipReportingIter.html
+    /// [`SkipReport`]: struct.SkipReport.html
+    /// [`EveryRangeError`]: enum.EveryRangeError.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeError, EveryRangeKind::*};
+    ///
+    /// let (iter, report) = vec![0..4, 2..6, 6..8, 8..12]
+    ///     .into_iter()
+    ///     .every_range(10)
+    ///     .report_skipped();
+    ///
+    /// let items = iter.collect::<Vec<_>>();
+    /// assert_eq!(items, [(Included, 0..4), (Excluded, 4..6), (Included, 6..8), (Excluded, 8..10)]);
+    ///
+    /// assert_eq!(
+    ///     report.skipped(),
+    ///     [
+    ///         (2..6, EveryRangeError::Overlapping { prev_end: 4, cur_start: 2 }),
+    ///         (8..12, EveryRangeError::ExceedsEnd { range_end: 12, iter_end: 10 }),
+    ///     ]
+    /// );
+    /// ```
+    pub fn report_skipped(self) -> (SkipReportingIter<I>, SkipReport) {
+        let report = SkipReport::default();
+
+        let (ranges, rebuild) = self.into_rebuild();
+        let ranges = ReportedRanges {
+            iter: ranges,
+            prev_end: rebuild.index,
+            end: rebuild.end,
+            report: report.clone(),
+        };
+
+        let iter = rebuild.build(ranges, rebuild.index, rebuild.end);
+
+        (SkipReportingIter { iter }, report)
+    }
+}
+
+impl<I> Iterator for SkipReportingIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+impl<I> FusedIterator for SkipReportingIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn report_skipped() {
+        let (mut iter, report) = [4..6, 0..2, 5..8, 8..10, 12..14, 11..10, 14..30, 16..15]
+            .iter()
+            .cloned()
+            .every_range(20)
+            .report_skipped();
+
+        assert_eq!(iter.next(), Some((Excluded, 0..4)));
+        assert!(report.is_empty());
+
+        assert_eq!(
+            iter.collect::<Vec<_>>(),
+            [
+                (Included, 4..6),
+                (Excluded, 6..8),
+                (Included, 8..10),
+                (Excluded, 10..12),
+                (Included, 12..14),
+                (Excluded, 14..20),
+            ]
+        );
+
+        assert_eq!(report.len(), 5);
+        assert_eq!(
+            report.skipped(),
+            [
+                (
+                    0..2,
+                    EveryRangeError::OutOfOrder {
+                        prev_end: 6,
+                        cur_start: 0,
+                    }
+                ),
+                (
+                    5..8,
+                    EveryRangeError::Overlapping {
+                        prev_end: 6,
+                        cur_start: 5,
+                    }
+                ),
+                (
+                    11..10,
+                    EveryRangeError::OutOfOrder {
+                        prev_end: 14,
+                        cur_start: 11,
+                    }
+                ),
+                (
+                    14..30,
+                    EveryRangeError::ExceedsEnd {
+                        range_end: 30,
+                        iter_end: 20,
+                    }
+                ),
+                (
+                    16..15,
+                    EveryRangeError::Reversed {
+                        range_start: 16,
+                        range_end: 15,
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn report_skipped_valid() {
+        let text = "Foo12Bar34Baz56";
+
+        let ranges = || {
+            text.match_indices(char::is_numeric)
+                .map(|(start, part)| start..(start + part.len()))
+        };
+
+        let (iter, report) = ranges().every_range(text.len()).report_skipped();
+
+        assert!(iter.eq(ranges().every_range(text.len())));
+        assert!(report.is_empty());
+    }
+
+    #[test]
+    fn report_skipped_skip_trailing() {
+        let (iter, report) = vec![2..4, 3..5]
+            .into_iter()
+            .every_range(10)
+            .skip_trailing()
+            .report_skipped();
+
+        let items = iter.collect::<Vec<_>>();
+        assert_eq!(items, [(Excluded, 0..2), (Included, 2..4)]);
+        assert_eq!(
+            report.skipped(),
+            [(
+                3..5,
+                EveryRangeError::Overlapping {
+                    prev_end: 4,
+                    cur_start: 3,
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn report_skipped_consumed_from_back() {
+        let mut iter = vec![2..4, 6..8].into_iter().every_range(10);
+        assert_eq!(iter.next_back(), Some((Excluded, 8..10)));
+
+        let (iter, report) = iter.report_skipped();
+
+        let items = iter.collect::<Vec<_>>();
+        assert_eq!(
+            items,
+            [
+                (Excluded, 0..2),
+                (Included, 2..4),
+                (Excluded, 4..6),
+                (Included, 6..8)
+            ]
+        );
+        assert!(report.is_empty());
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn report_skipped_matches_try_every_range() {
+        use crate::TryEveryRange;
+
+        let ranges = [0..4, 3..2];
+
+        let (iter, report) = ranges.iter().cloned().every_range(10).report_skipped();
+        iter.for_each(|_| {});
+
+        let err = ranges
+            .iter()
+            .cloned()
+            .try_every_range(10)
+            .find_map(Result::err);
+
+        assert_eq!(report.skipped(), [(3..2, err.unwrap())]);
+    }
+}
@@ -0,0 +1,237 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{DebugPlaceholder, EveryRangeIter};
+
+/// `BufferedSortRanges` sorts nearly-sorted [`Range`]s using
+/// a bounded min-heap.
+///
+/// This `struct` is used by [`BufferedSortedIter`], which is created
+/// by the [`every_range_buffered_sorted`] function on [`EveryRangeIter`].
+///
+/// [`Range`]: https://doc.rust-lang.org/stable/std/ops/struct.Range.html
+/// [`BufferedSortedIter`]: type.BufferedSortedIter.html
+/// [`every_range_buffered_sorted`]: struct.EveryRangeIter.html#method.every_range_buffered_sorted
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct BufferedSortRanges<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: I,
+    buffer_size: usize,
+    heap: BinaryHeap<Reverse<(usize, usize)>>,
+}
+
+impl<I> fmt::Debug for BufferedSortRanges<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufferedSortRanges")
+            .field("iter", &DebugPlaceholder("<iterator>"))
+            .field("buffer_size", &self.buffer_size)
+            .field("heap", &self.heap)
+            .finish()
+    }
+}
+
+/// `BufferedSortedIter` is an [`EveryRangeIter`] over nearly-sorted ranges.
+///
+/// This type is created by the [`every_range_buffered_sorted`]
+/// function on [`EveryRangeIter`].
+///
+/// [`every_range_buffered_sorted`]: struct.EveryRangeIter.html#method.every_range_buffered_sorted
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub type BufferedSortedIter<I> = EveryRangeIter<BufferedSortRanges<I>>;
+
+impl<I> BufferedSortedIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create an [`EveryRangeIter`] over nearly-sorted ranges.
+    ///
+    /// The ranges are sorted using a min-heap holding at most
+    /// `buffer_size` ranges. Thereby, `iter` is allowed to yield ranges
+    /// that are at most `buffer_size` positions out of order, without
+    /// having to collect all ranges into memory.
+    ///
+    /// Ranges are sorted by `start` and then by `end`.
+    ///
+    /// [`EveryRangeIter`]: struct.EveryRangeIter.html
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same conditions as [`EveryRangeIter`], i.e. if `iter`
+    /// yields ranges that are more than `buffer_size` positions out of order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRangeIter, EveryRangeKind::*};
+    ///
+    /// let ranges = vec![2..3, 0..1, 6..7, 4..5];
+    ///
+    /// let items = EveryRangeIter::every_range_buffered_sorted(ranges.into_iter(), 7, 1)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     items,
+    ///     [
+    ///         (Included, 0..1),
+    ///         (Excluded, 1..2),
+    ///         (Included, 2..3),
+    ///         (Excluded, 3..4),
+    ///         (Included, 4..5),
+    ///         (Excluded, 5..6),
+    ///         (Included, 6..7),
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    pub fn every_range_buffered_sorted(iter: I, end: usize, buffer_size: usize) -> Self {
+        let ranges = BufferedSortRanges {
+            iter,
+            buffer_size,
+            heap: BinaryHeap::with_capacity(buffer_size + 1),
+        };
+
+        EveryRangeIter::new(ranges, end)
+    }
+}
+
+impl<I> Iterator for BufferedSortRanges<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.heap.len() <= self.buffer_size {
+            match self.iter.next() {
+                Some(range) => self.heap.push(Reverse((range.start, range.end))),
+                None => break,
+            }
+        }
+
+        self.heap.pop().map(|Reverse((start, end))| start..end)
+    }
+}
+
+impl<I> FusedIterator for BufferedSortRanges<I> where I: FusedIterator<Item = Range<usize>> {}
+
+/// Sort `ranges` by `start`, and then by `end` for ranges
+/// with the same `start`.
+///
+/// *Note that overlapping ranges are kept, see [`merge_overlapping`]
+/// to merge them before passing them to [`every_range`].*
+///
+/// [`merge_overlapping`]: fn.merge_overlapping.html
+/// [`every_range`]: trait.EveryRange.html#method.every_range
+///
+/// # Example
+///
+/// ```
+/// use every_range::sort_ranges;
+///
+/// let ranges = sort_ranges(vec![6..8, 1..10, 2..8, 1..4]);
+/// assert_eq!(ranges, [1..4, 1..10, 2..8, 6..8]);
+/// ```
+#[inline]
+pub fn sort_ranges(mut ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    ranges.sort_by_key(|range| (range.start, range.end));
+    ranges
+}
+
+/// Sort `ranges` like [`sort_ranges`], and remove all ranges that are
+/// fully contained in another range, including duplicates.
+///
+/// *Note that partially overlapping ranges are kept, see
+/// [`merge_overlapping`] to merge them before passing them
+/// to [`every_range`].*
+///
+/// [`sort_ranges`]: fn.sort_ranges.html
+/// [`merge_overlapping`]: fn.merge_overlapping.html
+/// [`every_range`]: trait.EveryRange.html#method.every_range
+///
+/// # Example
+///
+/// ```
+/// use every_range::sort_and_dedup_ranges;
+///
+/// let ranges = sort_and_dedup_ranges(vec![6..8, 1..10, 2..8, 1..4, 9..12]);
+/// assert_eq!(ranges, [1..10, 9..12]);
+/// ```
+pub fn sort_and_dedup_ranges(mut ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    // Sort longer ranges first, such that ranges sharing
+    // a `start` are contained in the preceding range
+    ranges.sort_by_key(|range| (range.start, Reverse(range.end)));
+
+    let mut max_end = None;
+    ranges.retain(|range| match max_end {
+        Some(end) if range.end <= end => false,
+        _ => {
+            max_end = Some(range.end);
+            true
+        }
+    });
+
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EveryRange;
+
+    #[test]
+    fn every_range_buffered_sorted() {
+        let sorted = (0..100).map(|i| (i * 3)..(i * 3 + 2)).collect::<Vec<_>>();
+
+        let mut shuffled = sorted.clone();
+        for chunk in shuffled.chunks_mut(4) {
+            chunk.reverse();
+        }
+
+        let expected = sorted.into_iter().every_range(400).collect::<Vec<_>>();
+        let actual = EveryRangeIter::every_range_buffered_sorted(shuffled.into_iter(), 400, 3)
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    #[should_panic = "assertion failed: self.index <= next.start"]
+    fn every_range_buffered_sorted_insufficient_buffer() {
+        let ranges = [8..10, 6..8, 4..6, 0..2];
+
+        EveryRangeIter::every_range_buffered_sorted(ranges.iter().cloned(), 10, 2).for_each(|_| {});
+    }
+
+    #[test]
+    fn sort_ranges_contained() {
+        let ranges = vec![4..5, 11..12, 2..8, 1..10, 2..6];
+
+        let sorted = sort_ranges(ranges.clone());
+        assert_eq!(sorted, [1..10, 2..6, 2..8, 4..5, 11..12]);
+        assert_eq!(sort_ranges(sorted.clone()), sorted);
+
+        let deduped = sort_and_dedup_ranges(ranges);
+        assert_eq!(deduped, [1..10, 11..12]);
+        assert_eq!(sort_and_dedup_ranges(deduped.clone()), deduped);
+    }
+
+    #[test]
+    fn sort_and_dedup_ranges_duplicates() {
+        let ranges = vec![8..12, 0..2, 3..5, 0..2, 3..4, 10..11, 2..2];
+
+        let deduped = sort_and_dedup_ranges(ranges);
+        assert_eq!(deduped, [0..2, 3..5, 8..12]);
+        assert_eq!(sort_and_dedup_ranges(deduped.clone()), deduped);
+
+        let items = deduped.into_iter().every_range(12).count();
+        assert_eq!(items, 5);
+    }
+}
@@ -0,0 +1,213 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `SparseRegionIter` fuses nearby [`Included`] ranges into dense clusters.
+///
+/// This `struct` is created by the [`sparse_regions`]
+/// method on [`EveryRangeIter`].
+///
+/// [`sparse_regions`]: struct.EveryRangeIter.html#method.sparse_regions
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`Included`]: enum.EveryRangeKind.html#variant.Included
+pub struct SparseRegionIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+    min_density: f64,
+    /// The current cluster and the amount of included bytes within it.
+    cluster: Option<(Range<usize>, usize)>,
+    gap: Option<Range<usize>>,
+    queued: Option<(EveryRangeKind, Range<usize>)>,
+}
+
+impl<I> fmt::Debug for SparseRegionIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SparseRegionIter")
+            .field("iter", &self.iter)
+            .field("min_density", &self.min_density)
+            .field("cluster", &self.cluster)
+            .field("gap", &self.gap)
+            .field("queued", &self.queued)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`SparseRegionIter`], which groups consecutive
+    /// [`Included`] ranges into clusters, as long as the ratio of included
+    /// bytes to the total span of the cluster exceeds `min_density`.
+    ///
+    /// Each cluster is emitted as a single [`Included`] range covering
+    /// the whole cluster, i.e. gaps within a cluster become part of it.
+    /// Gaps between clusters remain as [`Excluded`] ranges.
+    ///
+    /// [`SparseRegionIter`]: struct.SparseRegionIter.html
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let regions = vec![0..2, 3..5, 10..11]
+    ///     .into_iter()
+    ///     .every_range(12)
+    ///     .sparse_regions(0.5)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     regions,
+    ///     [
+    ///         (Included, 0..5),
+    ///         (Excluded, 5..10),
+    ///         (Included, 10..11),
+    ///         (Excluded, 11..12),
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    pub fn sparse_regions(self, min_density: f64) -> SparseRegionIter<I> {
+        SparseRegionIter {
+            iter: self,
+            min_density,
+            cluster: None,
+            gap: None,
+            queued: None,
+        }
+    }
+}
+
+impl<I> SparseRegionIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Emit `cluster` and queue the pending gap after it.
+    #[inline]
+    fn flush(&mut self, cluster: Range<usize>) -> Option<(EveryRangeKind, Range<usize>)> {
+        if let Some(gap) = self.gap.take() {
+            self.queued = Some((EveryRangeKind::Excluded, gap));
+        }
+
+        Some((EveryRangeKind::Included, cluster))
+    }
+}
+
+impl<I> Iterator for SparseRegionIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(item) = self.queued.take() {
+            return Some(item);
+        }
+
+        loop {
+            match self.iter.next() {
+                Some((EveryRangeKind::Excluded, range)) => {
+                    if self.cluster.is_none() {
+                        return Some((EveryRangeKind::Excluded, range));
+                    }
+
+                    self.gap = Some(match self.gap.take() {
+                        Some(gap) => gap.start..range.end,
+                        None => range,
+                    });
+                }
+                Some((EveryRangeKind::Included, range)) => match self.cluster.take() {
+                    None => {
+                        self.cluster = Some((range.clone(), range.len()));
+                    }
+                    Some((cluster, included)) => {
+                        let included_merged = included + range.len();
+                        let span = range.end - cluster.start;
+
+                        let density = included_merged as f64 / span as f64;
+                        if span == 0 || density > self.min_density {
+                            self.cluster = Some((cluster.start..range.end, included_merged));
+                            self.gap = None;
+                        } else {
+                            self.cluster = Some((range.clone(), range.len()));
+                            return self.flush(cluster);
+                        }
+                    }
+                },
+                None => {
+                    let (cluster, _) = self.cluster.take()?;
+                    return self.flush(cluster);
+                }
+            }
+        }
+    }
+}
+
+impl<I> FusedIterator for SparseRegionIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn sparse_regions() {
+        let actual = [2..4, 5..7, 8..9, 15..17, 18..20]
+            .iter()
+            .cloned()
+            .every_range(25)
+            .sparse_regions(0.6)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Excluded, 0..2),
+                (Included, 2..9),
+                (Excluded, 9..15),
+                (Included, 15..20),
+                (Excluded, 20..25),
+            ]
+        );
+    }
+
+    #[test]
+    fn sparse_regions_density_one() {
+        let ranges = [2..4, 5..7, 8..9];
+
+        let actual = ranges
+            .iter()
+            .cloned()
+            .every_range(10)
+            .sparse_regions(1.0)
+            .collect::<Vec<_>>();
+        let expected = ranges.iter().cloned().every_range(10).collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn sparse_regions_density_zero() {
+        let actual = [2..4, 5..7, 8..9]
+            .iter()
+            .cloned()
+            .every_range(10)
+            .sparse_regions(0.0)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [(Excluded, 0..2), (Included, 2..9), (Excluded, 9..10)]
+        );
+    }
+}
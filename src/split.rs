@@ -0,0 +1,500 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+use std::vec;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `SplitOnLongGapIter` splits the items of an [`EveryRangeIter`]
+/// into chunks, separated by long [`Excluded`] ranges.
+///
+/// This `struct` is created by the [`split_on_excluded_longer_than`]
+/// method on [`EveryRangeIter`].
+///
+/// [`split_on_excluded_longer_than`]: struct.EveryRangeIter.html#method.split_on_excluded_longer_than
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+pub struct SplitOnLongGapIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+    threshold: usize,
+    split_index: usize,
+}
+
+impl<I> fmt::Debug for SplitOnLongGapIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitOnLongGapIter")
+            .field("iter", &self.iter)
+            .field("threshold", &self.threshold)
+            .field("split_index", &self.split_index)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`SplitOnLongGapIter`], which splits the items into
+    /// chunks at every [`Excluded`] range longer than `threshold`.
+    ///
+    /// Yields `(split_index, Vec<(EveryRangeKind, Range<usize>)>)` for
+    /// each chunk, where `split_index` is the 0-based index of the chunk.
+    /// The separating [`Excluded`] ranges are not part of any chunk,
+    /// and empty chunks are not yielded. Each chunk covers a contiguous
+    /// span of the original buffer.
+    ///
+    /// [`SplitOnLongGapIter`]: struct.SplitOnLongGapIter.html
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let chunks = vec![0..2, 3..5, 20..22]
+    ///     .into_iter()
+    ///     .every_range(22)
+    ///     .split_on_excluded_longer_than(5)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     chunks,
+    ///     [
+    ///         (0, vec![(Included, 0..2), (Excluded, 2..3), (Included, 3..5)]),
+    ///         (1, vec![(Included, 20..22)]),
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    pub fn split_on_excluded_longer_than(self, threshold: usize) -> SplitOnLongGapIter<I> {
+        SplitOnLongGapIter {
+            iter: self,
+            threshold,
+            split_index: 0,
+        }
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Split the `EveryRangeIter` at `pos`, returning all items covering
+    /// `..pos`, along with an `EveryRangeIter` covering `pos..end`.
+    ///
+    /// If a range straddles `pos`, then it is split in two, where the left
+    /// half is the last item in the returned [`Vec`], and the right half
+    /// is the first item yielded by the returned `EveryRangeIter`.
+    ///
+    /// Only the remaining items are split, i.e. if `pos` is before the
+    /// next item, then the returned [`Vec`] is empty, and if `pos` is after
+    /// the items remaining, then the returned `EveryRangeIter` is empty.
+    ///
+    /// *Note that the remaining input ranges are collected into a [`Vec`].*
+    ///
+    /// [`Vec`]: https://doc.rust-lang.org/stable/std/vec/struct.Vec.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let iter = vec![2..6].into_iter().every_range(8);
+    ///
+    /// let (left, right) = iter.bisect(4);
+    ///
+    /// assert_eq!(left, [(Excluded, 0..2), (Included, 2..4)]);
+    /// assert_eq!(right.collect::<Vec<_>>(), [(Included, 4..6), (Excluded, 6..8)]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn bisect(
+        mut self,
+        pos: usize,
+    ) -> (
+        Vec<(EveryRangeKind, Range<usize>)>,
+        EveryRangeIter<vec::IntoIter<Range<usize>>>,
+    ) {
+        let pos = pos.max(self.index).min(self.back_index);
+
+        let mut left = Vec::new();
+        let mut right_first = None;
+
+        for (kind, range) in &mut self {
+            if range.start >= pos {
+                if kind == EveryRangeKind::Included {
+                    right_first = Some(range);
+                }
+                break;
+            } else if range.end <= pos {
+                left.push((kind, range));
+            } else {
+                left.push((kind, range.start..pos));
+
+                if kind == EveryRangeKind::Included {
+                    right_first = Some(pos..range.end);
+                }
+                break;
+            }
+        }
+
+        let mut right = self.rebuild(|ranges| {
+            right_first
+                .into_iter()
+                .chain(ranges)
+                .collect::<Vec<_>>()
+                .into_iter()
+        });
+        right.index = pos;
+
+        (left, right)
+    }
+
+    /// Split `0..end` into `parts` approximately equal segments, and return
+    /// an independent `EveryRangeIter` for each segment, e.g. to process
+    /// the segments in parallel.
+    ///
+    /// Ranges that straddle a segment boundary are split across the
+    /// segments. Each returned `EveryRangeIter` covers exactly its segment.
+    ///
+    /// *Note that all items are consumed, and the [`Included`] ranges are
+    /// collected into a [`Vec`] per segment.*
+    ///
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Vec`]: https://doc.rust-lang.org/stable/std/vec/struct.Vec.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parts` is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let parts = vec![2..6].into_iter().every_range(8).n_way_split(2);
+    ///
+    /// let parts = parts
+    ///     .into_iter()
+    ///     .map(|part| part.collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     parts,
+    ///     [
+    ///         vec![(Excluded, 0..2), (Included, 2..4)],
+    ///         vec![(Included, 4..6), (Excluded, 6..8)],
+    ///     ]
+    /// );
+    /// ```
+    pub fn n_way_split(self, parts: usize) -> Vec<EveryRangeIter<vec::IntoIter<Range<usize>>>> {
+        assert!(parts != 0, "parts must not be 0");
+
+        let (ranges, rebuild) = self.into_rebuild();
+        let (start, end) = (rebuild.index, rebuild.end);
+        let len = end.saturating_sub(start);
+        let boundary = |i: usize| start + ((len as u128 * i as u128) / parts as u128) as usize;
+
+        let mut segments = vec![Vec::new(); parts];
+        let mut segment = 0;
+
+        for (kind, range) in rebuild.build(ranges, start, end) {
+            if kind != EveryRangeKind::Included {
+                continue;
+            }
+
+            loop {
+                let segment_end = boundary(segment + 1);
+
+                if (range.start < segment_end) || (segment + 1 == parts) {
+                    segments[segment]
+                        .push(range.start.max(boundary(segment))..range.end.min(segment_end));
+                }
+
+                if (range.end > segment_end)
+                    || ((range.start >= segment_end) && (segment + 1 < parts))
+                {
+                    segment += 1;
+                } else {
+                    break;
+                }
+            }
+        }
+
+        segments
+            .into_iter()
+            .enumerate()
+            .map(|(i, ranges)| rebuild.build(ranges.into_iter(), boundary(i), boundary(i + 1)))
+            .collect()
+    }
+}
+
+impl<I> Iterator for SplitOnLongGapIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (usize, Vec<(EveryRangeKind, Range<usize>)>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = Vec::new();
+
+        loop {
+            match self.iter.next() {
+                Some((EveryRangeKind::Excluded, range)) if range.len() > self.threshold => {
+                    if !chunk.is_empty() {
+                        break;
+                    }
+                }
+                Some(item) => chunk.push(item),
+                None if chunk.is_empty() => return None,
+                None => break,
+            }
+        }
+
+        let split_index = self.split_index;
+        self.split_index += 1;
+
+        Some((split_index, chunk))
+    }
+}
+
+impl<I> FusedIterator for SplitOnLongGapIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn split_on_excluded_longer_than() {
+        let text = "Foo Bar\n\n\n\nBaz  Qux\n\n\n\n";
+
+        let chunks = text
+            .match_indices(char::is_alphabetic)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .split_on_excluded_longer_than(2)
+            .map(|(i, chunk)| {
+                let start = chunk.first().unwrap().1.start;
+                let end = chunk.last().unwrap().1.end;
+                (i, &text[start..end])
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(chunks, [(0, "Foo Bar"), (1, "Baz  Qux")]);
+    }
+
+    #[test]
+    fn split_on_excluded_longer_than_leading() {
+        let chunks = [5..6, 7..8]
+            .iter()
+            .cloned()
+            .every_range(8)
+            .split_on_excluded_longer_than(0)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            chunks,
+            [(0, vec![(Included, 5..6)]), (1, vec![(Included, 7..8)])]
+        );
+    }
+
+    #[test]
+    fn bisect() {
+        let ranges = [3..5, 8..10, 13..15];
+        let expected = ranges.iter().cloned().every_range(15).collect::<Vec<_>>();
+
+        for pos in 0..=20 {
+            let (mut left, right) = ranges.iter().cloned().every_range(15).bisect(pos);
+
+            assert!(left.iter().all(|(_, range)| range.end <= pos));
+
+            let right = right.collect::<Vec<_>>();
+            assert!(right.iter().all(|(_, range)| range.start >= pos));
+
+            // Joining the split ranges back together results in the original
+            if let (Some(last), Some(first)) = (left.last_mut(), right.first()) {
+                if (last.0 == first.0) && (last.1.end == first.1.start) {
+                    last.1.end = first.1.end;
+                    left.extend(right.into_iter().skip(1));
+                } else {
+                    left.extend(right);
+                }
+            } else {
+                left.extend(right);
+            }
+
+            assert_eq!(left, expected, "pos = {}", pos);
+        }
+    }
+
+    #[test]
+    fn n_way_split() {
+        let text = "Foo12Bar34Baz56";
+
+        let parts = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .n_way_split(3)
+            .into_iter()
+            .map(|part| {
+                part.map(|(kind, range)| (kind, &text[range]))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            parts,
+            [
+                vec![(Excluded, "Foo"), (Included, "1"), (Included, "2")],
+                vec![(Excluded, "Bar"), (Included, "3"), (Included, "4")],
+                vec![(Excluded, "Baz"), (Included, "5"), (Included, "6")],
+            ]
+        );
+    }
+
+    #[test]
+    fn n_way_split_covers_segments() {
+        let ranges = [0..0, 1..3, 3..11, 12..13, 17..17];
+
+        for parts in 1..20 {
+            let segments = ranges.iter().cloned().every_range(17).n_way_split(parts);
+            assert_eq!(segments.len(), parts);
+
+            let mut index = 0;
+            let mut included = 0;
+            for segment in segments {
+                for (kind, range) in segment {
+                    assert_eq!(range.start, index);
+                    index = range.end;
+
+                    if kind == Included {
+                        included += range.len();
+                    }
+                }
+            }
+
+            assert_eq!(index, 17);
+            assert_eq!(included, 11);
+        }
+    }
+
+    #[test]
+    fn bisect_skip_trailing() {
+        let iter = std::iter::once(2..4).every_range(10).skip_trailing();
+        let (left, right) = iter.bisect(5);
+        assert_eq!(left, [(Excluded, 0..2), (Included, 2..4)]);
+        assert_eq!(right.collect::<Vec<_>>(), []);
+
+        let iter = std::iter::once(2..4).every_range(10).skip_trailing();
+        let (left, right) = iter.bisect(3);
+        assert_eq!(left, [(Excluded, 0..2), (Included, 2..3)]);
+        assert_eq!(right.collect::<Vec<_>>(), [(Included, 3..4)]);
+    }
+
+    #[test]
+    fn n_way_split_skip_trailing() {
+        let parts = vec![2..4, 6..8]
+            .into_iter()
+            .every_range(10)
+            .skip_trailing()
+            .n_way_split(2)
+            .into_iter()
+            .map(|part| part.collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            parts,
+            [
+                vec![(Excluded, 0..2), (Included, 2..4), (Excluded, 4..5)],
+                vec![(Excluded, 5..6), (Included, 6..8)],
+            ]
+        );
+    }
+
+    #[test]
+    fn bisect_consumed_from_back() {
+        let mut iter = vec![2..4, 6..8].into_iter().every_range(10);
+        assert_eq!(iter.next_back(), Some((Excluded, 8..10)));
+        assert_eq!(iter.next_back(), Some((Included, 6..8)));
+
+        let (left, right) = iter.bisect(5);
+        assert_eq!(left, [(Excluded, 0..2), (Included, 2..4), (Excluded, 4..5)]);
+        assert_eq!(right.collect::<Vec<_>>(), [(Excluded, 5..6)]);
+
+        let mut iter = vec![2..4, 6..8].into_iter().every_range(10);
+        assert_eq!(iter.next_back(), Some((Excluded, 8..10)));
+
+        let (_, right) = iter.bisect(5);
+        assert_eq!(
+            right.collect::<Vec<_>>(),
+            [(Excluded, 5..6), (Included, 6..8)]
+        );
+    }
+
+    #[test]
+    fn bisect_advanced() {
+        let mut iter = vec![2..4, 6..8].into_iter().every_range(10);
+        assert_eq!(iter.next(), Some((Excluded, 0..2)));
+        assert_eq!(iter.next(), Some((Included, 2..4)));
+        assert_eq!(iter.next(), Some((Excluded, 4..6)));
+
+        let (left, right) = iter.clone().bisect(3);
+        assert_eq!(left, []);
+        assert_eq!(
+            right.collect::<Vec<_>>(),
+            [(Included, 6..8), (Excluded, 8..10)]
+        );
+
+        let (left, right) = iter.bisect(7);
+        assert_eq!(left, [(Included, 6..7)]);
+        assert_eq!(
+            right.collect::<Vec<_>>(),
+            [(Included, 7..8), (Excluded, 8..10)]
+        );
+    }
+
+    #[test]
+    fn bisect_past_consumed_from_back() {
+        let mut iter = vec![2..4, 6..8].into_iter().every_range(10);
+        assert_eq!(iter.next_back(), Some((Excluded, 8..10)));
+
+        let (left, right) = iter.bisect(9);
+        assert_eq!(
+            left,
+            [
+                (Excluded, 0..2),
+                (Included, 2..4),
+                (Excluded, 4..6),
+                (Included, 6..8)
+            ]
+        );
+        assert_eq!(right.collect::<Vec<_>>(), []);
+    }
+
+    #[test]
+    fn n_way_split_consumed_from_back() {
+        let mut iter = vec![2..4, 6..8].into_iter().every_range(10);
+        iter.by_ref().rev().take(2).for_each(|_| {});
+
+        let parts = iter
+            .n_way_split(2)
+            .into_iter()
+            .map(|part| part.collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            parts,
+            [
+                vec![(Excluded, 0..2), (Included, 2..3)],
+                vec![(Included, 3..4), (Excluded, 4..6)],
+            ]
+        );
+    }
+}
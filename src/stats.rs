@@ -0,0 +1,598 @@
+use std::fmt;
+use std::ops::Range;
+
+use crate::{AssertNoOverlapIter, DedupByRangeIter, EveryRangeIter, EveryRangeKind};
+
+/// `SpanMeasurements` holds statistics of the lengths of the
+/// [`Included`] and [`Excluded`] ranges of an [`EveryRangeIter`].
+///
+/// All statistics are population statistics. If there are no
+/// ranges of a kind, then the statistics of that kind are `0.0`.
+///
+/// This `struct` is created by the [`measure_spans`]
+/// method on [`EveryRangeIter`].
+///
+/// [`measure_spans`]: struct.EveryRangeIter.html#method.measure_spans
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`Included`]: enum.EveryRangeKind.html#variant.Included
+/// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct SpanMeasurements {
+    /// The mean length of the [`Included`] ranges.
+    ///
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    pub mean_included_len: f64,
+
+    /// The standard deviation of the length of the [`Included`] ranges.
+    ///
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    pub std_dev_included_len: f64,
+
+    /// The mean length of the [`Excluded`] ranges.
+    ///
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    pub mean_excluded_len: f64,
+
+    /// The standard deviation of the length of the [`Excluded`] ranges.
+    ///
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    pub std_dev_excluded_len: f64,
+
+    /// The skewness of the length of the [`Included`] ranges.
+    ///
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    pub skewness_included: f64,
+
+    /// The skewness of the length of the [`Excluded`] ranges.
+    ///
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    pub skewness_excluded: f64,
+}
+
+/// `CoverageReport` summarizes the amount and total length of the
+/// [`Included`] and [`Excluded`] ranges yielded by an [`EveryRangeIter`].
+///
+/// The [`Display`] implementation renders the summary as a two-line table.
+///
+/// This `struct` is created by the [`report_coverage`]
+/// method on [`EveryRangeIter`].
+///
+/// [`report_coverage`]: struct.EveryRangeIter.html#method.report_coverage
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`Included`]: enum.EveryRangeKind.html#variant.Included
+/// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+/// [`Display`]: https://doc.rust-lang.org/stable/std/fmt/trait.Display.html
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub struct CoverageReport {
+    /// The `end` of the [`EveryRangeIter`].
+    ///
+    /// [`EveryRangeIter`]: struct.EveryRangeIter.html
+    pub end: usize,
+
+    /// The amount of [`Included`] ranges.
+    ///
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    pub included_ranges: usize,
+
+    /// The amount of [`Excluded`] ranges.
+    ///
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    pub excluded_ranges: usize,
+
+    /// The total length of the [`Included`] ranges.
+    ///
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    pub included_bytes: usize,
+
+    /// The total length of the [`Excluded`] ranges.
+    ///
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    pub excluded_bytes: usize,
+
+    /// The percentage of `included_bytes + excluded_bytes` covered by
+    /// [`Included`] ranges. If that is `0`, then this is `0.0`.
+    ///
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    pub coverage_pct: f64,
+}
+
+impl fmt::Display for CoverageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let total = self.included_bytes + self.excluded_bytes;
+        let excluded_pct = if total > 0 {
+            (self.excluded_bytes as f64 / total as f64) * 100.0
+        } else {
+            0.0
+        };
+
+        writeln!(
+            f,
+            "Included: {} ranges, {} bytes ({:.2}%)",
+            self.included_ranges, self.included_bytes, self.coverage_pct
+        )?;
+        write!(
+            f,
+            "Excluded: {} ranges, {} bytes ({:.2}%)",
+            self.excluded_ranges, self.excluded_bytes, excluded_pct
+        )
+    }
+}
+
+/// `EveryRangeStats` holds the amount and total length of the
+/// [`Included`] and [`Excluded`] ranges of an [`EveryRangeIter`].
+///
+/// This `struct` is created by the [`stats`] method on [`EveryRangeIter`]
+/// and [`EveryRangeExt`].
+///
+/// [`stats`]: struct.EveryRangeIter.html#method.stats
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`EveryRangeExt`]: trait.EveryRangeExt.html
+/// [`Included`]: enum.EveryRangeKind.html#variant.Included
+/// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+pub struct EveryRangeStats {
+    /// The amount of [`Included`] ranges.
+    ///
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    pub included_count: usize,
+
+    /// The amount of [`Excluded`] ranges.
+    ///
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    pub excluded_count: usize,
+
+    /// The total length of the [`Included`] ranges.
+    ///
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    pub included_total_len: usize,
+
+    /// The total length of the [`Excluded`] ranges.
+    ///
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    pub excluded_total_len: usize,
+}
+
+impl EveryRangeStats {
+    /// Returns the total length of all ranges.
+    #[inline]
+    pub fn total_len(&self) -> usize {
+        self.included_total_len + self.excluded_total_len
+    }
+
+    /// Returns the ratio of the total length covered by [`Included`]
+    /// ranges, in `0.0..=1.0`. If the total length is `0`, then
+    /// this is `0.0`.
+    ///
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    #[inline]
+    pub fn coverage_ratio(&self) -> f64 {
+        match self.total_len() {
+            0 => 0.0,
+            total_len => self.included_total_len as f64 / total_len as f64,
+        }
+    }
+}
+
+/// Trait which implements `stats` and other adapters for any [`Iterator`]
+/// over the items of an [`EveryRangeIter`], e.g. the adapters of
+/// [`EveryRangeIter`] or items mapped by [`map`].
+///
+/// [`Iterator`]: https://doc.rust-lang.org/stable/std/iter/trait.Iterator.html
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`map`]: https://doc.rust-lang.org/stable/std/iter/trait.Iterator.html#method.map
+pub trait EveryRangeExt: Sized + Iterator<Item = (EveryRangeKind, Range<usize>)> {
+    /// Consume the iterator and compute [`EveryRangeStats`].
+    ///
+    /// [`EveryRangeStats`]: struct.EveryRangeStats.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeExt};
+    ///
+    /// let stats = vec![2..4, 6..8]
+    ///     .into_iter()
+    ///     .every_range(10)
+    ///     .with_kind_filter(true, false)
+    ///     .stats();
+    ///
+    /// assert_eq!(stats.included_count, 2);
+    /// assert_eq!(stats.excluded_count, 0);
+    /// assert_eq!(stats.coverage_ratio(), 1.0);
+    /// ```
+    fn stats(self) -> EveryRangeStats {
+        let mut stats = EveryRangeStats::default();
+
+        for (kind, range) in self {
+            match kind {
+                EveryRangeKind::Included => {
+                    stats.included_count += 1;
+                    stats.included_total_len += range.len();
+                }
+                EveryRangeKind::Excluded => {
+                    stats.excluded_count += 1;
+                    stats.excluded_total_len += range.len();
+                }
+            }
+        }
+
+        stats
+    }
+
+    /// Create an [`AssertNoOverlapIter`], which asserts that every
+    /// yielded range starts where the previous range ended, i.e.
+    /// `prev.end == next.start`.
+    ///
+    /// This is useful as a correctness check in pipelines with
+    /// custom adapters. In release builds, i.e. without
+    /// `debug_assertions`, items are passed through unchecked.
+    ///
+    /// [`AssertNoOverlapIter`]: struct.AssertNoOverlapIter.html
+    ///
+    /// # Panics
+    ///
+    /// With `debug_assertions` enabled, panics if a range
+    /// does not start where the previous range ended.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeExt};
+    ///
+    /// let count = vec![2..4, 6..8]
+    ///     .into_iter()
+    ///     .every_range(10)
+    ///     .map(|(kind, range)| (kind, (range.start * 2)..(range.end * 2)))
+    ///     .assert_no_overlapping_output()
+    ///     .count();
+    ///
+    /// assert_eq!(count, 5);
+    /// ```
+    #[inline]
+    fn assert_no_overlapping_output(self) -> AssertNoOverlapIter<Self> {
+        AssertNoOverlapIter::new(self)
+    }
+
+    /// Create a [`DedupByRangeIter`], which drops any item with the same
+    /// range as the previous item, regardless of kind. The first item of
+    /// consecutive equal ranges is kept.
+    ///
+    /// Consecutive equal ranges are e.g. produced by mapping
+    /// the items, such that their ranges are clamped.
+    ///
+    /// [`DedupByRangeIter`]: struct.DedupByRangeIter.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeExt, EveryRangeKind::*};
+    ///
+    /// let items = vec![2..4, 6..8]
+    ///     .into_iter()
+    ///     .every_range(10)
+    ///     .map(|(kind, range)| (kind, range.start.min(5)..range.end.min(5)))
+    ///     .dedup_by_range()
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Excluded, 0..2), (Included, 2..4), (Excluded, 4..5), (Included, 5..5)]);
+    /// ```
+    #[inline]
+    fn dedup_by_range(self) -> DedupByRangeIter<Self> {
+        DedupByRangeIter::new(self, false)
+    }
+
+    /// Create a [`DedupByRangeIter`], which drops any item with the same
+    /// kind and range as the previous item.
+    ///
+    /// See [`dedup_by_range`] for deduplicating regardless of kind.
+    ///
+    /// [`DedupByRangeIter`]: struct.DedupByRangeIter.html
+    /// [`dedup_by_range`]: trait.EveryRangeExt.html#method.dedup_by_range
+    #[inline]
+    fn dedup_by_range_and_kind(self) -> DedupByRangeIter<Self> {
+        DedupByRangeIter::new(self, true)
+    }
+}
+
+impl<T> EveryRangeExt for T where T: Iterator<Item = (EveryRangeKind, Range<usize>)> {}
+
+/// Online computation of the mean, variance, and skewness,
+/// using Welford's algorithm extended to the third moment.
+#[derive(Default)]
+struct Moments {
+    n: f64,
+    mean: f64,
+    m2: f64,
+    m3: f64,
+}
+
+impl Moments {
+    fn push(&mut self, x: f64) {
+        let n1 = self.n;
+        self.n += 1.0;
+
+        let delta = x - self.mean;
+        let delta_n = delta / self.n;
+        let term1 = delta * delta_n * n1;
+
+        self.mean += delta_n;
+        self.m3 += (term1 * delta_n * (self.n - 2.0)) - (3.0 * delta_n * self.m2);
+        self.m2 += term1;
+    }
+
+    fn std_dev(&self) -> f64 {
+        if self.n > 0.0 {
+            (self.m2 / self.n).sqrt()
+        } else {
+            0.0
+        }
+    }
+
+    fn skewness(&self) -> f64 {
+        if self.m2 > 0.0 {
+            self.n.sqrt() * self.m3 / self.m2.powf(1.5)
+        } else {
+            0.0
+        }
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Consume the `EveryRangeIter` and compute [`SpanMeasurements`]
+    /// of the lengths of the [`Included`] and [`Excluded`] ranges.
+    ///
+    /// The statistics are computed in a single pass.
+    ///
+    /// [`SpanMeasurements`]: struct.SpanMeasurements.html
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::EveryRange;
+    ///
+    /// let spans = vec![0..2, 3..7].into_iter().every_range(8).measure_spans();
+    ///
+    /// assert_eq!(spans.mean_included_len, 3.0);
+    /// assert_eq!(spans.std_dev_included_len, 1.0);
+    /// assert_eq!(spans.mean_excluded_len, 1.0);
+    /// assert_eq!(spans.std_dev_excluded_len, 0.0);
+    /// ```
+    pub fn measure_spans(self) -> SpanMeasurements {
+        let mut included = Moments::default();
+        let mut excluded = Moments::default();
+
+        for (kind, range) in self {
+            let len = range.len() as f64;
+
+            match kind {
+                EveryRangeKind::Included => included.push(len),
+                EveryRangeKind::Excluded => excluded.push(len),
+            }
+        }
+
+        SpanMeasurements {
+            mean_included_len: included.mean,
+            std_dev_included_len: included.std_dev(),
+            mean_excluded_len: excluded.mean,
+            std_dev_excluded_len: excluded.std_dev(),
+            skewness_included: included.skewness(),
+            skewness_excluded: excluded.skewness(),
+        }
+    }
+
+    /// Consume the `EveryRangeIter` and compute [`EveryRangeStats`].
+    ///
+    /// *See also [`EveryRangeExt`], which implements `stats`
+    /// for the adapters of `EveryRangeIter`.*
+    ///
+    /// [`EveryRangeStats`]: struct.EveryRangeStats.html
+    /// [`EveryRangeExt`]: trait.EveryRangeExt.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::EveryRange;
+    ///
+    /// let stats = vec![2..4, 6..8].into_iter().every_range(10).stats();
+    ///
+    /// assert_eq!(stats.included_count, 2);
+    /// assert_eq!(stats.excluded_count, 3);
+    /// assert_eq!(stats.total_len(), 10);
+    /// assert_eq!(stats.coverage_ratio(), 0.4);
+    /// ```
+    #[inline]
+    pub fn stats(self) -> EveryRangeStats {
+        EveryRangeExt::stats(self)
+    }
+
+    /// Consume the `EveryRangeIter` and create a [`CoverageReport`]
+    /// summarizing the amount and total length of the [`Included`]
+    /// and [`Excluded`] ranges.
+    ///
+    /// The report is computed in a single pass.
+    ///
+    /// [`CoverageReport`]: struct.CoverageReport.html
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::EveryRange;
+    ///
+    /// let report = vec![0..2, 3..5].into_iter().every_range(8).report_coverage();
+    ///
+    /// assert_eq!(report.included_ranges, 2);
+    /// assert_eq!(report.excluded_bytes, 4);
+    /// assert_eq!(report.coverage_pct, 50.0);
+    ///
+    /// assert_eq!(
+    ///     report.to_string(),
+    ///     "Included: 2 ranges, 4 bytes (50.00%)\nExcluded: 2 ranges, 4 bytes (50.00%)"
+    /// );
+    /// ```
+    pub fn report_coverage(self) -> CoverageReport {
+        let mut report = CoverageReport {
+            end: self.end,
+            ..CoverageReport::default()
+        };
+
+        for (kind, range) in self {
+            match kind {
+                EveryRangeKind::Included => {
+                    report.included_ranges += 1;
+                    report.included_bytes += range.len();
+                }
+                EveryRangeKind::Excluded => {
+                    report.excluded_ranges += 1;
+                    report.excluded_bytes += range.len();
+                }
+            }
+        }
+
+        let total = report.included_bytes + report.excluded_bytes;
+        if total > 0 {
+            report.coverage_pct = (report.included_bytes as f64 / total as f64) * 100.0;
+        }
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Range;
+
+    use super::EveryRangeStats;
+    use crate::EveryRange;
+
+    fn assert_approx_eq(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn measure_spans() {
+        // Included lengths: 1, 1, 1, 5
+        // Excluded lengths: 1, 2, 3
+        let spans = [0..1, 2..3, 5..6, 9..14]
+            .iter()
+            .cloned()
+            .every_range(14)
+            .measure_spans();
+
+        assert_approx_eq(spans.mean_included_len, 2.0);
+        assert_approx_eq(spans.std_dev_included_len, 3.0f64.sqrt());
+        assert_approx_eq(spans.skewness_included, 2.0 / 3.0f64.sqrt());
+
+        assert_approx_eq(spans.mean_excluded_len, 2.0);
+        assert_approx_eq(spans.std_dev_excluded_len, (2.0f64 / 3.0).sqrt());
+        assert_approx_eq(spans.skewness_excluded, 0.0);
+    }
+
+    #[test]
+    fn measure_spans_empty() {
+        let spans = Vec::new().into_iter().every_range(0).measure_spans();
+        assert_eq!(spans, Default::default());
+    }
+
+    #[test]
+    fn report_coverage() {
+        let text = "Foo12Bar34Baz56";
+
+        let report = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .report_coverage();
+
+        assert_eq!(report.end, 15);
+        assert_eq!(report.included_ranges, 6);
+        assert_eq!(report.excluded_ranges, 3);
+        assert_eq!(report.included_bytes, 6);
+        assert_eq!(report.excluded_bytes, 9);
+        assert_approx_eq(report.coverage_pct, 40.0);
+
+        assert_eq!(
+            report.to_string(),
+            "Included: 6 ranges, 6 bytes (40.00%)\nExcluded: 3 ranges, 9 bytes (60.00%)"
+        );
+    }
+
+    #[test]
+    fn report_coverage_from() {
+        let report = vec![4..6, 8..10]
+            .into_iter()
+            .every_range_from(2, 12)
+            .skip_trailing()
+            .report_coverage();
+
+        assert_eq!(report.end, 12);
+        assert_eq!(report.included_bytes, 4);
+        assert_eq!(report.excluded_bytes, 4);
+        assert_approx_eq(report.coverage_pct, 50.0);
+
+        assert_eq!(
+            report.to_string(),
+            "Included: 2 ranges, 4 bytes (50.00%)\nExcluded: 2 ranges, 4 bytes (50.00%)"
+        );
+    }
+
+    #[test]
+    fn report_coverage_empty() {
+        let report = Vec::new().into_iter().every_range(0).report_coverage();
+
+        assert_eq!(report, Default::default());
+        assert_eq!(
+            report.to_string(),
+            "Included: 0 ranges, 0 bytes (0.00%)\nExcluded: 0 ranges, 0 bytes (0.00%)"
+        );
+    }
+
+    #[test]
+    fn stats() {
+        let text = "Foo12Bar34Baz56";
+
+        let stats = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .stats();
+
+        assert_eq!(
+            stats,
+            EveryRangeStats {
+                included_count: 6,
+                excluded_count: 3,
+                included_total_len: 6,
+                excluded_total_len: 9,
+            }
+        );
+        assert_eq!(
+            stats.included_total_len + stats.excluded_total_len,
+            text.len()
+        );
+        assert_approx_eq(stats.coverage_ratio(), 0.4);
+    }
+
+    #[test]
+    fn stats_coverage_ratio_bounds() {
+        let cases: [(&[Range<usize>], usize); 4] = [
+            (&[], 0),
+            (&[], 10),
+            (&[0..5, 5..10], 10),
+            (&[2..4, 4..4, 9..10], 10),
+        ];
+
+        for &(ranges, end) in &cases {
+            let stats = ranges.iter().cloned().every_range(end).stats();
+
+            assert_eq!(stats.total_len(), end);
+            assert!((0.0..=1.0).contains(&stats.coverage_ratio()));
+        }
+    }
+}
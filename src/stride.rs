@@ -0,0 +1,174 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::coalesce::Coalesce;
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `StrideIncludedIter` keeps every `step`-th [`Included`] range,
+/// and converts the others into [`Excluded`] ranges.
+///
+/// This `struct` is created by the [`stride_included`]
+/// method on [`EveryRangeIter`].
+///
+/// [`stride_included`]: struct.EveryRangeIter.html#method.stride_included
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`Included`]: enum.EveryRangeKind.html#variant.Included
+/// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+pub struct StrideIncludedIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+    step: usize,
+    /// The amount of `Included` ranges to drop, before keeping the next.
+    skip: usize,
+    coalesce: Coalesce,
+}
+
+impl<I> fmt::Debug for StrideIncludedIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StrideIncludedIter")
+            .field("iter", &self.iter)
+            .field("step", &self.step)
+            .field("skip", &self.skip)
+            .field("coalesce", &self.coalesce)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`StrideIncludedIter`], which keeps every `step`-th
+    /// [`Included`] range, i.e. the 0th, `step`th, `2 * step`th, etc.
+    ///
+    /// The dropped [`Included`] ranges are converted into [`Excluded`]
+    /// ranges and merged with the adjacent [`Excluded`] ranges.
+    /// A `step` of `1` yields all items unchanged.
+    ///
+    /// [`StrideIncludedIter`]: struct.StrideIncludedIter.html
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![0..1, 2..3, 4..5]
+    ///     .into_iter()
+    ///     .every_range(6)
+    ///     .stride_included(2)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Included, 0..1), (Excluded, 1..4), (Included, 4..5), (Excluded, 5..6)]);
+    /// ```
+    #[inline]
+    pub fn stride_included(self, step: usize) -> StrideIncludedIter<I> {
+        assert!(step != 0);
+
+        StrideIncludedIter {
+            iter: self,
+            step,
+            skip: 0,
+            coalesce: Coalesce::only(EveryRangeKind::Excluded),
+        }
+    }
+}
+
+impl<I> Iterator for StrideIncludedIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (kind, range) in &mut self.iter {
+            let kind = match kind {
+                EveryRangeKind::Included => {
+                    let keep = self.skip == 0;
+                    self.skip = if keep { self.step - 1 } else { self.skip - 1 };
+
+                    if keep {
+                        EveryRangeKind::Included
+                    } else {
+                        EveryRangeKind::Excluded
+                    }
+                }
+                EveryRangeKind::Excluded => EveryRangeKind::Excluded,
+            };
+
+            if let Some(item) = self.coalesce.push((kind, range)) {
+                return Some(item);
+            }
+        }
+
+        self.coalesce.finish()
+    }
+}
+
+impl<I> FusedIterator for StrideIncludedIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn stride_included() {
+        let text = "Foo12Bar34Baz56";
+
+        let actual = text
+            .match_indices(char::is_alphabetic)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .stride_included(3)
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Included, "F"),
+                (Excluded, "oo12"),
+                (Included, "B"),
+                (Excluded, "ar34"),
+                (Included, "B"),
+                (Excluded, "az56"),
+            ]
+        );
+    }
+
+    #[test]
+    fn stride_included_one() {
+        let text = "Foo12Bar34Baz56";
+
+        let ranges = || {
+            text.match_indices(char::is_alphabetic)
+                .map(|(start, part)| start..(start + part.len()))
+        };
+
+        let actual = ranges()
+            .every_range(text.len())
+            .stride_included(1)
+            .collect::<Vec<_>>();
+        let expected = ranges().every_range(text.len()).collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    #[should_panic = "assertion failed: step != 0"]
+    fn stride_included_zero() {
+        std::iter::once(0..1).every_range(1).stride_included(0);
+    }
+}
@@ -0,0 +1,175 @@
+use std::fmt;
+use std::iter::{self, FusedIterator};
+use std::ops::Range;
+
+use crate::{DebugPlaceholder, EveryRangeIter, EveryRangeKind};
+
+/// `TaggedEveryRangeIter` is an [`EveryRangeIter`] over tagged ranges,
+/// which yields the tag along with each [`Included`] range.
+///
+/// This `struct` is created by the [`every_range_tagged`]
+/// method on [`EveryRangeTagged`].
+///
+/// [`every_range_tagged`]: trait.EveryRangeTagged.html#method.every_range_tagged
+/// [`EveryRangeTagged`]: trait.EveryRangeTagged.html
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`Included`]: enum.EveryRangeKind.html#variant.Included
+pub struct TaggedEveryRangeIter<I, T>
+where
+    I: Iterator<Item = (Range<usize>, T)>,
+{
+    iter: I,
+    /// Only ever holds the pending range of `iter`,
+    /// while `tag` holds its tag.
+    ranges: EveryRangeIter<iter::Empty<Range<usize>>>,
+    tag: Option<T>,
+}
+
+impl<I, T> fmt::Debug for TaggedEveryRangeIter<I, T>
+where
+    I: Iterator<Item = (Range<usize>, T)>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaggedEveryRangeIter")
+            .field("iter", &DebugPlaceholder("<iterator>"))
+            .field("ranges", &self.ranges)
+            .field("tag", &self.tag.as_ref().map(|_| DebugPlaceholder("<tag>")))
+            .finish()
+    }
+}
+
+impl<I, T> Iterator for TaggedEveryRangeIter<I, T>
+where
+    I: Iterator<Item = (Range<usize>, T)>,
+{
+    type Item = (EveryRangeKind, Range<usize>, Option<T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.ranges.next.is_none() {
+            if let Some((range, tag)) = self.iter.next() {
+                self.ranges.next = Some(range);
+                self.tag = Some(tag);
+            }
+        }
+
+        let (kind, range) = self.ranges.next()?;
+
+        let tag = match kind {
+            EveryRangeKind::Included => self.tag.take(),
+            EveryRangeKind::Excluded => None,
+        };
+
+        Some((kind, range, tag))
+    }
+}
+
+impl<I, T> FusedIterator for TaggedEveryRangeIter<I, T> where I: Iterator<Item = (Range<usize>, T)> {}
+
+/// Trait which implements `every_range_tagged` to get a `TaggedEveryRangeIter`.
+///
+/// *[See `EveryRangeIter` for more information.][`EveryRangeIter`]*
+///
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub trait EveryRangeTagged<T>: Sized + Iterator<Item = (Range<usize>, T)> {
+    /// Create a [`TaggedEveryRangeIter`] with `end`, the same as
+    /// [`every_range`], but over `(Range<usize>, T)` items, e.g.
+    /// the tokens of a tokenizer.
+    ///
+    /// Each [`Included`] range is yielded with `Some(tag)`,
+    /// while the generated [`Excluded`] ranges are yielded with `None`.
+    ///
+    /// [`TaggedEveryRangeIter`]: struct.TaggedEveryRangeIter.html
+    /// [`every_range`]: trait.EveryRange.html#method.every_range
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRangeKind::*, EveryRangeTagged};
+    ///
+    /// let items = vec![(2..4, 'a'), (6..8, 'b')]
+    ///     .into_iter()
+    ///     .every_range_tagged(10)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     items,
+    ///     [
+    ///         (Excluded, 0..2, None),
+    ///         (Included, 2..4, Some('a')),
+    ///         (Excluded, 4..6, None),
+    ///         (Included, 6..8, Some('b')),
+    ///         (Excluded, 8..10, None),
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    fn every_range_tagged(self, end: usize) -> TaggedEveryRangeIter<Self, T> {
+        TaggedEveryRangeIter {
+            iter: self,
+            ranges: EveryRangeIter::new(iter::empty(), end),
+            tag: None,
+        }
+    }
+}
+
+impl<I, T> EveryRangeTagged<T> for I where I: Iterator<Item = (Range<usize>, T)> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EveryRangeKind::*;
+
+    #[derive(PartialEq, Debug)]
+    struct NumberToken(u32);
+
+    #[test]
+    fn every_range_tagged() {
+        let text = "hello 42 world";
+
+        let tokens = text.split(' ').scan(0, |start, word| {
+            let range = *start..(*start + word.len());
+            *start = range.end + 1;
+            Some((range, word))
+        });
+        let numbers =
+            tokens.filter_map(|(range, word)| Some((range, NumberToken(word.parse().ok()?))));
+
+        let actual = numbers
+            .every_range_tagged(text.len())
+            .map(|(kind, range, tag)| (kind, &text[range], tag))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Excluded, "hello ", None),
+                (Included, "42", Some(NumberToken(42))),
+                (Excluded, " world", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn every_range_tagged_adjacent() {
+        let actual = vec![(0..2, 1), (2..4, 2)]
+            .into_iter()
+            .every_range_tagged(4)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [(Included, 0..2, Some(1)), (Included, 2..4, Some(2))]
+        );
+    }
+
+    #[test]
+    #[should_panic = "assertion failed: self.index <= next.start"]
+    fn every_range_tagged_out_of_order() {
+        vec![(4..6, ()), (0..2, ())]
+            .into_iter()
+            .every_range_tagged(10)
+            .for_each(|_| {});
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `TakeWhileShortGapIter` yields items until a long [`Excluded`]
+/// range is encountered.
+///
+/// This `struct` is created by the [`take_while_gap_shorter_than`]
+/// method on [`EveryRangeIter`].
+///
+/// [`take_while_gap_shorter_than`]: struct.EveryRangeIter.html#method.take_while_gap_shorter_than
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+pub struct TakeWhileShortGapIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+    max_gap: usize,
+    done: bool,
+}
+
+impl<I> fmt::Debug for TakeWhileShortGapIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TakeWhileShortGapIter")
+            .field("iter", &self.iter)
+            .field("max_gap", &self.max_gap)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`TakeWhileShortGapIter`], which yields items while all
+    /// [`Excluded`] ranges are shorter than `max_gap`.
+    ///
+    /// When an [`Excluded`] range with a length of at least `max_gap`
+    /// is encountered, then it is yielded as the final item.
+    ///
+    /// [`TakeWhileShortGapIter`]: struct.TakeWhileShortGapIter.html
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let items = vec![0..2, 3..5, 10..12]
+    ///     .into_iter()
+    ///     .every_range(12)
+    ///     .take_while_gap_shorter_than(2)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     items,
+    ///     [(Included, 0..2), (Excluded, 2..3), (Included, 3..5), (Excluded, 5..10)]
+    /// );
+    /// ```
+    #[inline]
+    pub fn take_while_gap_shorter_than(self, max_gap: usize) -> TakeWhileShortGapIter<I> {
+        TakeWhileShortGapIter {
+            iter: self,
+            max_gap,
+            done: false,
+        }
+    }
+}
+
+impl<I> Iterator for TakeWhileShortGapIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let (kind, range) = self.iter.next()?;
+
+        if (kind == EveryRangeKind::Excluded) && (range.len() >= self.max_gap) {
+            self.done = true;
+        }
+
+        Some((kind, range))
+    }
+}
+
+impl<I> FusedIterator for TakeWhileShortGapIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn take_while_gap_shorter_than() {
+        let text = "Foo Bar\n\nBaz Qux";
+
+        let mut iter = text
+            .match_indices(char::is_alphabetic)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .take_while_gap_shorter_than(2)
+            .map(|(kind, range)| (kind, &text[range]));
+
+        let block = iter
+            .by_ref()
+            .filter(|(kind, _)| *kind == Included)
+            .map(|(_, part)| part)
+            .collect::<String>();
+
+        assert_eq!(block, "FooBar");
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn take_while_gap_shorter_than_no_long_gap() {
+        let ranges = [3..5, 8..10];
+
+        let actual = ranges
+            .iter()
+            .cloned()
+            .every_range(12)
+            .take_while_gap_shorter_than(4)
+            .collect::<Vec<_>>();
+        let expected = ranges.iter().cloned().every_range(12).collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+}
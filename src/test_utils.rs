@@ -0,0 +1,68 @@
+//! Test utilities, available with the `test-utils` feature.
+
+use std::ops::Range;
+
+use crate::{EveryRange, EveryRangeKind};
+
+/// Assert that collecting an [`EveryRangeIter`] over `ranges` and
+/// `end`, results in a contiguous partition of `0..end`, where the
+/// [`Included`] ranges are exactly the original `ranges`.
+///
+/// [`EveryRangeIter`]: ../struct.EveryRangeIter.html
+/// [`Included`]: ../enum.EveryRangeKind.html#variant.Included
+///
+/// # Panics
+///
+/// Panics if the invariant does not hold, or in the same
+/// conditions as [`EveryRangeIter`].
+///
+/// # Example
+///
+/// ```
+/// use every_range::test_utils::roundtrip_test;
+///
+/// roundtrip_test(vec![2..4, 4..4, 6..8], 10);
+/// ```
+pub fn roundtrip_test(ranges: Vec<Range<usize>>, end: usize) {
+    let items = ranges
+        .clone()
+        .into_iter()
+        .every_range(end)
+        .collect::<Vec<_>>();
+
+    let mut index = 0;
+    for (_, range) in &items {
+        assert_eq!(index, range.start, "ranges are not contiguous: {:?}", items);
+        index = range.end;
+    }
+    assert_eq!(index, end);
+
+    let included = items
+        .into_iter()
+        .filter(|(kind, _)| *kind == EveryRangeKind::Included)
+        .map(|(_, range)| range)
+        .collect::<Vec<_>>();
+
+    assert_eq!(included, ranges);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        roundtrip_test(vec![], 0);
+        roundtrip_test(vec![], 10);
+        roundtrip_test(vec![0..5, 5..10], 10);
+        roundtrip_test(vec![0..0, 0..0, 10..10], 10);
+        roundtrip_test(vec![3..5, 8..10, 13..15], 15);
+        roundtrip_test(vec![3..5, 5..8, 13..15], 20);
+    }
+
+    #[test]
+    #[should_panic = "assertion failed: self.index <= next.start"]
+    fn roundtrip_out_of_order() {
+        roundtrip_test(vec![4..6, 0..2], 10);
+    }
+}
@@ -0,0 +1,140 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+use std::thread;
+use std::time::Duration;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `ThrottledIter` rate-limits an [`EveryRangeIter`] by sleeping
+/// between items.
+///
+/// This `struct` is created by the [`throttle`]
+/// method on [`EveryRangeIter`].
+///
+/// [`throttle`]: struct.EveryRangeIter.html#method.throttle
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct ThrottledIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+    delay: Duration,
+    started: bool,
+}
+
+impl<I> fmt::Debug for ThrottledIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThrottledIter")
+            .field("iter", &self.iter)
+            .field("delay", &self.delay)
+            .field("started", &self.started)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`ThrottledIter`], which yields at most `limit_per_second`
+    /// items per second, by calling [`thread::sleep`] between items.
+    ///
+    /// This is intended for testing and benchmarking, to simulate a slow
+    /// producer without changing the producer itself.
+    ///
+    /// *This requires the `testing` feature to be enabled.*
+    ///
+    /// [`ThrottledIter`]: struct.ThrottledIter.html
+    /// [`thread::sleep`]: https://doc.rust-lang.org/stable/std/thread/fn.sleep.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit_per_second` is not greater than `0.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::EveryRange;
+    ///
+    /// let count = vec![2..4, 6..8]
+    ///     .into_iter()
+    ///     .every_range(10)
+    ///     .throttle(1000.0)
+    ///     .count();
+    ///
+    /// assert_eq!(count, 5);
+    /// ```
+    #[inline]
+    pub fn throttle(self, limit_per_second: f64) -> ThrottledIter<I> {
+        assert!(
+            limit_per_second > 0.0,
+            "limit_per_second must be greater than 0"
+        );
+
+        ThrottledIter {
+            iter: self,
+            delay: Duration::from_secs_f64(1.0 / limit_per_second),
+            started: false,
+        }
+    }
+}
+
+impl<I> Iterator for ThrottledIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+
+        if self.started {
+            thread::sleep(self.delay);
+        } else {
+            self.started = true;
+        }
+
+        Some(item)
+    }
+}
+
+impl<I> FusedIterator for ThrottledIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn throttle() {
+        let text = "Foo12Bar34Baz56";
+
+        let expected = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .collect::<Vec<_>>();
+
+        let now = Instant::now();
+        let actual = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .throttle(500.0)
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+        assert!(now.elapsed() >= Duration::from_millis(2 * (expected.len() as u64 - 1)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn throttle_zero() {
+        Vec::new().into_iter().every_range(0).throttle(0.0);
+    }
+}
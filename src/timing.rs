@@ -0,0 +1,136 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+use std::time::{Duration, Instant};
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `TimedIter` annotates each item with the time elapsed
+/// since the previous item.
+///
+/// This `struct` is created by the [`with_elapsed_time`]
+/// method on [`EveryRangeIter`].
+///
+/// [`with_elapsed_time`]: struct.EveryRangeIter.html#method.with_elapsed_time
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct TimedIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+    last: Instant,
+}
+
+impl<I> fmt::Debug for TimedIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TimedIter")
+            .field("iter", &self.iter)
+            .field("last", &self.last)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`TimedIter`], which yields
+    /// `(EveryRangeKind, Range<usize>, Duration)`, where the [`Duration`]
+    /// is the time elapsed since the previous item was yielded.
+    /// For the first item, it is the time since `with_elapsed_time`
+    /// was called.
+    ///
+    /// Thereby the [`Duration`] includes the time spent by the consumer
+    /// processing the previous item.
+    ///
+    /// *This requires the `timing` feature to be enabled.*
+    ///
+    /// [`TimedIter`]: struct.TimedIter.html
+    /// [`Duration`]: https://doc.rust-lang.org/stable/std/time/struct.Duration.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use every_range::EveryRange;
+    ///
+    /// let total = vec![2..4, 6..8]
+    ///     .into_iter()
+    ///     .every_range(10)
+    ///     .with_elapsed_time()
+    ///     .map(|(_, _, elapsed)| elapsed)
+    ///     .sum::<Duration>();
+    ///
+    /// println!("{:?}", total);
+    /// ```
+    #[inline]
+    pub fn with_elapsed_time(self) -> TimedIter<I> {
+        TimedIter {
+            iter: self,
+            last: Instant::now(),
+        }
+    }
+}
+
+impl<I> Iterator for TimedIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>, Duration);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (kind, range) = self.iter.next()?;
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last);
+        self.last = now;
+
+        Some((kind, range, elapsed))
+    }
+}
+
+impl<I> FusedIterator for TimedIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn with_elapsed_time() {
+        let text = "Foo12Bar34Baz56";
+
+        let expected = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .collect::<Vec<_>>();
+
+        let iter = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .with_elapsed_time();
+
+        let mut actual = Vec::new();
+        for (kind, range, elapsed) in iter {
+            if actual.len() == 2 {
+                assert!(elapsed >= Duration::from_millis(5));
+            }
+
+            actual.push((kind, range));
+
+            if actual.len() == 2 {
+                thread::sleep(Duration::from_millis(5));
+            }
+        }
+
+        assert_eq!(actual, expected);
+    }
+}
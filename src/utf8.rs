@@ -0,0 +1,539 @@
+use std::error;
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{EveryRangeIter, EveryRangeKind, RemainingRanges};
+
+/// `Utf8BoundaryError` is returned when a [`Range`] does not
+/// start or end on a UTF-8 character boundary of a text.
+///
+/// [`Range`]: https://doc.rust-lang.org/stable/std/ops/struct.Range.html
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Utf8BoundaryError {
+    /// The first range that is not on a character boundary.
+    pub offending_range: Range<usize>,
+
+    /// The byte position in `offending_range`, which is not
+    /// on a character boundary.
+    pub byte_pos: usize,
+}
+
+impl fmt::Display for Utf8BoundaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "range {:?} is not on a character boundary at byte {}",
+            self.offending_range, self.byte_pos
+        )
+    }
+}
+
+impl error::Error for Utf8BoundaryError {}
+
+/// Check that every range in `ranges` starts and ends on
+/// a UTF-8 character boundary of `text`.
+pub(crate) fn check_boundaries<I>(text: &str, ranges: I) -> Result<(), Utf8BoundaryError>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    for range in ranges {
+        for &byte_pos in &[range.start, range.end] {
+            if !text.is_char_boundary(byte_pos) {
+                return Err(Utf8BoundaryError {
+                    offending_range: range,
+                    byte_pos,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// `CharAwareIter` is an [`EveryRangeIter`] over a text, where
+/// every range is guaranteed to be on UTF-8 character boundaries.
+///
+/// This `struct` is created by the [`every_range_char_aware`]
+/// function on [`EveryRangeIter`].
+///
+/// [`every_range_char_aware`]: struct.EveryRangeIter.html#method.every_range_char_aware
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct CharAwareIter<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+    text: &'a str,
+}
+
+impl<'a, I> fmt::Debug for CharAwareIter<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CharAwareIter")
+            .field("iter", &self.iter)
+            .field("text", &self.text)
+            .finish()
+    }
+}
+
+impl<'a, I> CharAwareIter<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Returns the text that the ranges were validated against.
+    #[inline]
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+}
+
+/// Returns the length of the UTF-8 sequence started by `byte`,
+/// or `1` if `byte` is ASCII or a continuation byte.
+#[inline]
+fn utf8_seq_len(byte: u8) -> usize {
+    match byte {
+        0xF0..=0xFF => 4,
+        0xE0..=0xEF => 3,
+        0xC0..=0xDF => 2,
+        _ => 1,
+    }
+}
+
+/// Extends single byte ranges starting a multi-byte character,
+/// such that they cover the complete character.
+struct SnappedRanges<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: RemainingRanges<I>,
+    text: &'a str,
+    /// The bytes added by extending the previous range, if any.
+    extended: Range<usize>,
+    end: usize,
+}
+
+impl<'a, I> Iterator for SnappedRanges<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let mut range = self.iter.next()?;
+
+            // Ranges that started within a previously extended character
+            // are truncated to start after it, or skipped if they end
+            // within it. Any other invalid range is left for
+            // `EveryRangeIter` to panic on
+            if self.extended.contains(&range.start) {
+                if range.end <= self.extended.end {
+                    continue;
+                }
+
+                range.start = self.extended.end;
+            }
+
+            self.extended = range.end..range.end;
+
+            if range.len() == 1 {
+                if let Some(&byte) = self.text.as_bytes().get(range.start) {
+                    let len = utf8_seq_len(byte);
+                    range.end = (range.start + len).min(self.end).min(self.text.len());
+                    self.extended.end = range.end;
+                }
+            }
+
+            return Some(range);
+        }
+    }
+}
+
+/// `FlatSingleCharIter` extends single byte [`Included`] ranges
+/// to cover complete UTF-8 characters.
+///
+/// This `struct` is created by the [`flatten_single_char_included`]
+/// method on [`EveryRangeIter`].
+///
+/// [`flatten_single_char_included`]: struct.EveryRangeIter.html#method.flatten_single_char_included
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`Included`]: enum.EveryRangeKind.html#variant.Included
+pub struct FlatSingleCharIter<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<SnappedRanges<'a, I>>,
+}
+
+impl<'a, I> fmt::Debug for FlatSingleCharIter<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FlatSingleCharIter")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`CharAwareIter`] over `text` with `text.len()` as the end.
+    ///
+    /// All `ranges` are validated up front, and if any range does not
+    /// start and end on a UTF-8 character boundary of `text`, then
+    /// [`Utf8BoundaryError`] is returned. Ranges exceeding `text.len()`
+    /// are also reported as an error. Thereby all produced ranges are
+    /// safe to use with `&text[range]`.
+    ///
+    /// [`CharAwareIter`]: struct.CharAwareIter.html
+    /// [`Utf8BoundaryError`]: struct.Utf8BoundaryError.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::EveryRangeIter;
+    ///
+    /// let text = "Føø Bar";
+    ///
+    /// let iter = EveryRangeIter::every_range_char_aware(text, vec![0..5].into_iter()).unwrap();
+    /// for (_, range) in iter {
+    ///     println!("{:?}", &text[range]);
+    /// }
+    ///
+    /// // `2` is inside "ø"
+    /// let err = EveryRangeIter::every_range_char_aware(text, vec![0..2].into_iter());
+    /// assert!(err.is_err());
+    /// ```
+    pub fn every_range_char_aware(
+        text: &str,
+        ranges: I,
+    ) -> Result<CharAwareIter<'_, I>, Utf8BoundaryError>
+    where
+        I: Clone,
+    {
+        check_boundaries(text, ranges.clone())?;
+
+        Ok(CharAwareIter {
+            iter: EveryRangeIter::new(ranges, text.len()),
+            text,
+        })
+    }
+
+    /// Create a [`FlatSingleCharIter`], which snaps [`Included`] ranges
+    /// to complete characters of `text`. If an [`Included`] range is
+    /// exactly one byte, and that byte starts a multi-byte UTF-8 sequence,
+    /// then the range is extended to cover the complete character.
+    ///
+    /// If a following range starts within an extended character, then it
+    /// is truncated to start after the character, or skipped if it also
+    /// ends within the character. Otherwise, ranges received out of order
+    /// or overlapping panic, the same as they do for [`EveryRangeIter`].
+    ///
+    /// [`FlatSingleCharIter`]: struct.FlatSingleCharIter.html
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`EveryRangeIter`]: struct.EveryRangeIter.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let text = "Føø";
+    ///
+    /// let items = vec![1..2]
+    ///     .into_iter()
+    ///     .every_range(text.len())
+    ///     .flatten_single_char_included(text)
+    ///     .map(|(kind, range)| (kind, &text[range]))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Excluded, "F"), (Included, "ø"), (Excluded, "ø")]);
+    /// ```
+    pub fn flatten_single_char_included(self, text: &str) -> FlatSingleCharIter<'_, I> {
+        let (ranges, rebuild) = self.into_rebuild();
+        let ranges = SnappedRanges {
+            iter: ranges,
+            text,
+            extended: rebuild.index..rebuild.index,
+            end: rebuild.end,
+        };
+
+        FlatSingleCharIter {
+            iter: rebuild.build(ranges, rebuild.index, rebuild.end),
+        }
+    }
+
+    /// Validate that all remaining input ranges start and end on a UTF-8
+    /// character boundary of `text`, returning the `EveryRangeIter`
+    /// unchanged if they do. Ranges exceeding `text.len()` are also
+    /// reported as an error.
+    ///
+    /// Only the cloned input iterator is traversed, so iteration itself
+    /// has no additional overhead afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, Utf8BoundaryError};
+    ///
+    /// let text = "Føø Bar";
+    ///
+    /// let iter = vec![0..5].into_iter().every_range(text.len());
+    /// assert!(iter.check_utf8_boundaries(text).is_ok());
+    ///
+    /// let iter = vec![4..8].into_iter().every_range(text.len());
+    /// assert_eq!(
+    ///     iter.check_utf8_boundaries(text).err(),
+    ///     Some(Utf8BoundaryError {
+    ///         offending_range: 4..8,
+    ///         byte_pos: 4,
+    ///     })
+    /// );
+    /// ```
+    pub fn check_utf8_boundaries(self, text: &str) -> Result<Self, Utf8BoundaryError>
+    where
+        I: Clone,
+    {
+        let ranges = self.next.clone().into_iter().chain(self.iter.clone());
+        check_boundaries(text, ranges.chain(self.back.clone()))?;
+
+        Ok(self)
+    }
+}
+
+impl<'a, I> Iterator for CharAwareIter<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+impl<'a, I> FusedIterator for CharAwareIter<'a, I> where I: Iterator<Item = Range<usize>> {}
+
+impl<'a, I> Iterator for FlatSingleCharIter<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+impl<'a, I> FusedIterator for FlatSingleCharIter<'a, I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn every_range_char_aware() {
+        let text = "Føø Bår";
+
+        let iter =
+            EveryRangeIter::every_range_char_aware(text, [0..5, 6..10].iter().cloned()).unwrap();
+        assert_eq!(iter.text(), text);
+
+        let actual = iter
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [(Included, "Føø"), (Excluded, " "), (Included, "Bår")]
+        );
+    }
+
+    #[test]
+    fn every_range_char_aware_err() {
+        let text = "Føø Bår";
+
+        let err = EveryRangeIter::every_range_char_aware(text, [0..1, 6..8].iter().cloned())
+            .err()
+            .unwrap();
+        assert_eq!(
+            err,
+            Utf8BoundaryError {
+                offending_range: 6..8,
+                byte_pos: 8,
+            }
+        );
+
+        let err = EveryRangeIter::every_range_char_aware(text, std::iter::once(0..20))
+            .err()
+            .unwrap();
+        assert_eq!(err.byte_pos, 20);
+    }
+
+    #[test]
+    fn check_utf8_boundaries() {
+        let text = "Føø Bår";
+
+        let mut iter = [0..5, 6..8].iter().cloned().every_range(text.len());
+        assert_eq!(iter.next(), Some((Included, 0..5)));
+        assert_eq!(iter.next(), Some((Excluded, 5..6)));
+
+        let err = iter.check_utf8_boundaries(text).err().unwrap();
+        assert_eq!(
+            err,
+            Utf8BoundaryError {
+                offending_range: 6..8,
+                byte_pos: 8,
+            }
+        );
+
+        let iter = [0..5, 6..10].iter().cloned().every_range(text.len());
+        let actual = iter
+            .check_utf8_boundaries(text)
+            .unwrap()
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            [(Included, "Føø"), (Excluded, " "), (Included, "Bår")]
+        );
+    }
+
+    #[test]
+    fn flatten_single_char_included() {
+        let text = "Føø Bår 🦀";
+
+        let actual = text
+            .bytes()
+            .enumerate()
+            .filter(|&(_, byte)| byte >= 0xC0)
+            .map(|(i, _)| i..(i + 1))
+            .every_range(text.len())
+            .flatten_single_char_included(text)
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Excluded, "F"),
+                (Included, "ø"),
+                (Included, "ø"),
+                (Excluded, " B"),
+                (Included, "å"),
+                (Excluded, "r "),
+                (Included, "🦀"),
+            ]
+        );
+    }
+
+    #[test]
+    fn flatten_single_char_included_truncates() {
+        let text = "Føø";
+
+        let actual = [1..2, 2..3, 3..5]
+            .iter()
+            .cloned()
+            .every_range(text.len())
+            .flatten_single_char_included(text)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [(Excluded, 0..1), (Included, 1..3), (Included, 3..5)]
+        );
+
+        let actual = [1..2, 2..4]
+            .iter()
+            .cloned()
+            .every_range(text.len())
+            .flatten_single_char_included(text)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [(Excluded, 0..1), (Included, 1..3), (Included, 3..5)]
+        );
+    }
+
+    #[test]
+    #[should_panic = "assertion failed: self.index <= next.start"]
+    fn flatten_single_char_included_out_of_order() {
+        let text = "Føø";
+
+        [1..2, 0..1]
+            .iter()
+            .cloned()
+            .every_range(text.len())
+            .flatten_single_char_included(text)
+            .for_each(|_| {});
+    }
+
+    #[test]
+    #[should_panic = "assertion failed: self.index <= next.start"]
+    fn flatten_single_char_included_overlapping() {
+        let text = "Føø";
+
+        [1..3, 2..5]
+            .iter()
+            .cloned()
+            .every_range(text.len())
+            .flatten_single_char_included(text)
+            .for_each(|_| {});
+    }
+
+    #[test]
+    fn flatten_single_char_included_skip_trailing() {
+        let text = "Føø";
+
+        let items = std::iter::once(1..2)
+            .every_range(text.len())
+            .skip_trailing()
+            .flatten_single_char_included(text)
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(items, [(Excluded, "F"), (Included, "ø")]);
+    }
+
+    #[test]
+    fn flatten_single_char_included_consumed_from_back() {
+        let text = "Føø";
+
+        let mut iter = vec![1..2, 3..5].into_iter().every_range(text.len());
+        assert_eq!(iter.next_back(), Some((Included, 3..5)));
+
+        let items = iter
+            .flatten_single_char_included(text)
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(items, [(Excluded, "F"), (Included, "ø")]);
+    }
+
+    #[test]
+    fn check_utf8_boundaries_consumed_from_back() {
+        let text = "Føø";
+
+        let mut iter = std::iter::once(0..2).every_range(text.len());
+        assert_eq!(iter.next_back(), Some((Excluded, 2..5)));
+
+        assert_eq!(
+            iter.check_utf8_boundaries(text).err(),
+            Some(Utf8BoundaryError {
+                offending_range: 0..2,
+                byte_pos: 2,
+            })
+        );
+    }
+}
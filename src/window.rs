@@ -0,0 +1,291 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+use std::vec;
+
+use crate::{EveryRangeIter, EveryRangeKind, Rebuild, RemainingRanges};
+
+/// `WindowedEveryRangeIter` yields overlapping windows, where each
+/// window is an [`EveryRangeIter`] over its part of the ranges.
+///
+/// This `struct` is created by the [`every_range_windowed`]
+/// method on [`EveryRangeIter`].
+///
+/// [`every_range_windowed`]: struct.EveryRangeIter.html#method.every_range_windowed
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct WindowedEveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: Option<EveryRangeIter<RemainingRanges<I>>>,
+    rebuild: Rebuild<usize>,
+    ranges: Vec<Range<usize>>,
+    /// The index of the first range, that can overlap the next window.
+    first: usize,
+    window_start: usize,
+    window_size: usize,
+    step: usize,
+    end: usize,
+    done: bool,
+}
+
+impl<I> fmt::Debug for WindowedEveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WindowedEveryRangeIter")
+            .field("iter", &self.iter)
+            .field("rebuild", &self.rebuild)
+            .field("ranges", &self.ranges)
+            .field("first", &self.first)
+            .field("window_start", &self.window_start)
+            .field("window_size", &self.window_size)
+            .field("step", &self.step)
+            .field("end", &self.end)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`WindowedEveryRangeIter`], which slides a window of
+    /// `window_size` bytes over `0..end`, moving it `step` bytes at a time.
+    ///
+    /// For each window an `(window_start, iter)` pair is yielded, where
+    /// `iter` is an [`EveryRangeIter`] over the [`Included`] ranges clipped
+    /// to the window, i.e. it covers exactly the window. The last window
+    /// is the first one reaching `end`, and might be shorter than
+    /// `window_size`.
+    ///
+    /// *Note that all [`Included`] ranges are collected into a [`Vec`],
+    /// upon the first call to `next`.*
+    ///
+    /// [`WindowedEveryRangeIter`]: struct.WindowedEveryRangeIter.html
+    /// [`EveryRangeIter`]: struct.EveryRangeIter.html
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Vec`]: https://doc.rust-lang.org/stable/std/vec/struct.Vec.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window_size` or `step` is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let windows = vec![2..5]
+    ///     .into_iter()
+    ///     .every_range(8)
+    ///     .every_range_windowed(4, 3)
+    ///     .map(|(start, iter)| (start, iter.collect::<Vec<_>>()))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     windows,
+    ///     [
+    ///         (0, vec![(Excluded, 0..2), (Included, 2..4)]),
+    ///         (3, vec![(Included, 3..5), (Excluded, 5..7)]),
+    ///         (6, vec![(Excluded, 6..8)]),
+    ///     ]
+    /// );
+    /// ```
+    #[inline]
+    pub fn every_range_windowed(
+        self,
+        window_size: usize,
+        step: usize,
+    ) -> WindowedEveryRangeIter<I> {
+        assert!(window_size != 0, "window_size must not be 0");
+        assert!(step != 0, "step must not be 0");
+
+        let (ranges, rebuild) = self.into_rebuild();
+
+        WindowedEveryRangeIter {
+            window_start: rebuild.index,
+            end: rebuild.end,
+            iter: Some(rebuild.build(ranges, rebuild.index, rebuild.end)),
+            rebuild,
+            ranges: Vec::new(),
+            first: 0,
+            window_size,
+            step,
+            done: false,
+        }
+    }
+}
+
+impl<I> Iterator for WindowedEveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (usize, EveryRangeIter<vec::IntoIter<Range<usize>>>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(iter) = self.iter.take() {
+            self.ranges = iter
+                .filter(|(kind, _)| *kind == EveryRangeKind::Included)
+                .map(|(_, range)| range)
+                .collect();
+        }
+
+        if self.done {
+            return None;
+        }
+
+        let start = self.window_start;
+        let end = start.saturating_add(self.window_size).min(self.end);
+
+        while let Some(range) = self.ranges.get(self.first) {
+            if (range.end > start) || (range.start == start) {
+                break;
+            }
+            self.first += 1;
+        }
+
+        let window = self.ranges[self.first..]
+            .iter()
+            .take_while(|range| (range.start < end) || ((range.start == end) && (end == self.end)))
+            .map(|range| range.start.max(start)..range.end.min(end))
+            .collect::<Vec<_>>();
+
+        if end >= self.end {
+            self.done = true;
+        } else {
+            self.window_start += self.step;
+        }
+
+        Some((start, self.rebuild.build(window.into_iter(), start, end)))
+    }
+}
+
+impl<I> FusedIterator for WindowedEveryRangeIter<I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn every_range_windowed() {
+        let text = "Foo12Bar34Baz56";
+
+        let windows = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .every_range_windowed(6, 5)
+            .map(|(start, iter)| {
+                let window = iter
+                    .map(|(kind, range)| (kind, &text[range]))
+                    .collect::<Vec<_>>();
+                (start, window)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            windows,
+            [
+                (
+                    0,
+                    vec![
+                        (Excluded, "Foo"),
+                        (Included, "1"),
+                        (Included, "2"),
+                        (Excluded, "B")
+                    ]
+                ),
+                (
+                    5,
+                    vec![
+                        (Excluded, "Bar"),
+                        (Included, "3"),
+                        (Included, "4"),
+                        (Excluded, "B")
+                    ]
+                ),
+                (
+                    10,
+                    vec![(Excluded, "Baz"), (Included, "5"), (Included, "6")]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn every_range_windowed_covers_windows() {
+        let ranges = [1..3, 4..9, 12..13, 15..15];
+
+        for &(window_size, step) in &[(1, 1), (3, 2), (4, 4), (5, 7), (20, 1)] {
+            let windows = ranges
+                .iter()
+                .cloned()
+                .every_range(15)
+                .every_range_windowed(window_size, step);
+
+            for (start, iter) in windows {
+                let items = iter.collect::<Vec<_>>();
+                let end = (start + window_size).min(15);
+
+                assert_eq!(items.first().unwrap().1.start, start);
+                assert_eq!(items.last().unwrap().1.end, end);
+                assert!(items.windows(2).all(|w| w[0].1.end == w[1].1.start));
+            }
+        }
+    }
+
+    #[test]
+    fn every_range_windowed_empty() {
+        let mut iter = Vec::new()
+            .into_iter()
+            .every_range(0)
+            .every_range_windowed(4, 2);
+
+        let (start, window) = iter.next().unwrap();
+        assert_eq!(start, 0);
+        assert_eq!(window.count(), 0);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn every_range_windowed_skip_trailing() {
+        let windows = std::iter::once(2..5)
+            .every_range(8)
+            .skip_trailing()
+            .every_range_windowed(4, 3)
+            .map(|(start, iter)| (start, iter.collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            windows,
+            [
+                (0, vec![(Excluded, 0..2), (Included, 2..4)]),
+                (3, vec![(Included, 3..5), (Excluded, 5..7)]),
+                (6, vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn every_range_windowed_consumed_from_back() {
+        let mut iter = vec![2..4, 6..8].into_iter().every_range(10);
+        iter.by_ref().rev().take(2).for_each(|_| {});
+
+        let windows = iter
+            .every_range_windowed(4, 3)
+            .map(|(start, iter)| (start, iter.collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            windows,
+            [
+                (0, vec![(Excluded, 0..2), (Included, 2..4)]),
+                (3, vec![(Included, 3..4), (Excluded, 4..6)]),
+            ]
+        );
+    }
+}
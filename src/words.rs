@@ -0,0 +1,550 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{EveryRangeIter, EveryRangeKind, RemainingRanges};
+
+#[inline]
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Returns `true` if the character ending at `pos` is a word character.
+#[inline]
+fn is_word_before(text: &str, pos: usize) -> bool {
+    matches!(text.get(..pos).and_then(|s| s.chars().next_back()), Some(c) if is_word_char(c))
+}
+
+/// Returns `true` if the character starting at `pos` is a word character.
+#[inline]
+fn is_word_at(text: &str, pos: usize) -> bool {
+    matches!(text.get(pos..).and_then(|s| s.chars().next()), Some(c) if is_word_char(c))
+}
+
+/// Moves `pos` backward to the start of the word it is within,
+/// without moving before `min`.
+fn word_start(text: &str, mut pos: usize, min: usize) -> usize {
+    while pos > min && is_word_before(text, pos) {
+        pos -= text[..pos].chars().next_back().map_or(1, char::len_utf8);
+    }
+    pos
+}
+
+/// Moves `pos` forward to the end of the word it is within,
+/// without moving past `max`.
+fn word_end(text: &str, mut pos: usize, max: usize) -> usize {
+    while pos < max && is_word_at(text, pos) {
+        pos += text[pos..].chars().next().map_or(1, char::len_utf8);
+    }
+    pos
+}
+
+/// Contracts the ranges to word boundaries, and optionally skips
+/// the ones that become empty.
+struct ContractedRanges<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: RemainingRanges<I>,
+    text: &'a str,
+    skip_empty: bool,
+}
+
+impl<'a, I> ContractedRanges<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn contract(&self, range: Range<usize>) -> Range<usize> {
+        let text = self.text;
+
+        let mut start = range.start;
+        if is_word_before(text, start) && is_word_at(text, start) {
+            start = word_end(text, start, range.end);
+        }
+        while start < range.end && !is_word_at(text, start) {
+            start += text
+                .get(start..)
+                .and_then(|s| s.chars().next())
+                .map_or(1, char::len_utf8);
+        }
+
+        let mut end = range.end;
+        if is_word_before(text, end) && is_word_at(text, end) {
+            end = word_start(text, end, start);
+        }
+        while end > start && !is_word_before(text, end) {
+            end -= text
+                .get(..end)
+                .and_then(|s| s.chars().next_back())
+                .map_or(1, char::len_utf8);
+        }
+
+        if start < end {
+            start..end
+        } else {
+            start..start
+        }
+    }
+}
+
+impl<'a, I> Iterator for ContractedRanges<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let range = self.iter.next()?;
+            let range = self.contract(range);
+
+            if !self.skip_empty || (range.start < range.end) {
+                return Some(range);
+            }
+        }
+    }
+}
+
+/// Expands the ranges to word boundaries, and merges the ones
+/// that overlap or touch afterwards.
+struct ExpandedRanges<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: RemainingRanges<I>,
+    text: &'a str,
+    bounds: Range<usize>,
+    pending: Option<Range<usize>>,
+}
+
+impl<'a, I> ExpandedRanges<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    #[inline]
+    fn expand(&self, range: Range<usize>) -> Range<usize> {
+        let start = word_start(self.text, range.start, self.bounds.start);
+        let end = word_end(self.text, range.end, self.bounds.end);
+        start..end
+    }
+
+    #[inline]
+    fn next_expanded(&mut self) -> Option<Range<usize>> {
+        let range = self.iter.next()?;
+        Some(self.expand(range))
+    }
+}
+
+impl<'a, I> Iterator for ExpandedRanges<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut range = match self.pending.take() {
+            Some(range) => range,
+            None => self.next_expanded()?,
+        };
+
+        while let Some(next) = self.next_expanded() {
+            if next.start <= range.end {
+                range.end = range.end.max(next.end);
+            } else {
+                self.pending = Some(next);
+                break;
+            }
+        }
+
+        Some(range)
+    }
+}
+
+/// `WordBoundaryIter` expands [`Included`] ranges to word boundaries.
+///
+/// This `struct` is created by the [`expand_to_word_boundaries`]
+/// method on [`EveryRangeIter`].
+///
+/// [`expand_to_word_boundaries`]: struct.EveryRangeIter.html#method.expand_to_word_boundaries
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`Included`]: enum.EveryRangeKind.html#variant.Included
+pub struct WordBoundaryIter<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<ExpandedRanges<'a, I>>,
+}
+
+impl<'a, I> fmt::Debug for WordBoundaryIter<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WordBoundaryIter")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`WordBoundaryIter`], which expands each [`Included`]
+    /// range outward, such that it starts and ends on a word boundary
+    /// of `text`. Word characters are alphanumeric characters and `_`,
+    /// everything else (e.g. whitespace and punctuation) separates words.
+    ///
+    /// If a range starts mid-word, then it is extended backward to the
+    /// start of that word. If a range ends mid-word, then it is extended
+    /// forward to the end of that word. [`Included`] ranges that overlap
+    /// or touch after expansion are coalesced into one.
+    ///
+    /// [`WordBoundaryIter`]: struct.WordBoundaryIter.html
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let text = "Foo Bar Baz";
+    ///
+    /// let items = vec![5..6]
+    ///     .into_iter()
+    ///     .every_range(text.len())
+    ///     .expand_to_word_boundaries(text)
+    ///     .map(|(kind, range)| (kind, &text[range]))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Excluded, "Foo "), (Included, "Bar"), (Excluded, " Baz")]);
+    /// ```
+    pub fn expand_to_word_boundaries(self, text: &str) -> WordBoundaryIter<'_, I> {
+        let (ranges, rebuild) = self.into_rebuild();
+        let ranges = ExpandedRanges {
+            iter: ranges,
+            text,
+            bounds: rebuild.index..rebuild.end,
+            pending: None,
+        };
+
+        WordBoundaryIter {
+            iter: rebuild.build(ranges, rebuild.index, rebuild.end),
+        }
+    }
+}
+
+impl<'a, I> Iterator for WordBoundaryIter<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+impl<'a, I> FusedIterator for WordBoundaryIter<'a, I> where I: Iterator<Item = Range<usize>> {}
+
+/// `WordContractIter` contracts [`Included`] ranges to word boundaries.
+///
+/// This `struct` is created by the [`contract_to_word_boundaries`]
+/// method on [`EveryRangeIter`].
+///
+/// [`contract_to_word_boundaries`]: struct.EveryRangeIter.html#method.contract_to_word_boundaries
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+/// [`Included`]: enum.EveryRangeKind.html#variant.Included
+pub struct WordContractIter<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<ContractedRanges<'a, I>>,
+}
+
+impl<'a, I> fmt::Debug for WordContractIter<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WordContractIter")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<'a, I> WordContractIter<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Set whether [`Included`] ranges that become empty after contraction
+    /// are skipped, which is the default. If `skip_empty` is `false`, then
+    /// they are emitted as zero-length [`Included`] ranges instead.
+    ///
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    #[inline]
+    pub fn skip_empty(mut self, skip_empty: bool) -> Self {
+        self.iter.iter.skip_empty = skip_empty;
+        self
+    }
+}
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Create a [`WordContractIter`], which is the inverse of
+    /// [`expand_to_word_boundaries`]. It shrinks each [`Included`] range
+    /// inward, such that it only covers whole words of `text`.
+    ///
+    /// If a range starts mid-word or on a non-word character, then it is
+    /// advanced to the next word start. If a range ends mid-word or after
+    /// a non-word character, then it is retreated to the previous word end.
+    /// Ranges that become empty are skipped, unless [`skip_empty`] is
+    /// turned off, in which case they are emitted as zero-length ranges.
+    ///
+    /// [`WordContractIter`]: struct.WordContractIter.html
+    /// [`expand_to_word_boundaries`]: struct.EveryRangeIter.html#method.expand_to_word_boundaries
+    /// [`skip_empty`]: struct.WordContractIter.html#method.skip_empty
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use every_range::{EveryRange, EveryRangeKind::*};
+    ///
+    /// let text = "Foo Bar Baz";
+    ///
+    /// let items = vec![1..10]
+    ///     .into_iter()
+    ///     .every_range(text.len())
+    ///     .contract_to_word_boundaries(text)
+    ///     .map(|(kind, range)| (kind, &text[range]))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(items, [(Excluded, "Foo "), (Included, "Bar"), (Excluded, " Baz")]);
+    /// ```
+    pub fn contract_to_word_boundaries(self, text: &str) -> WordContractIter<'_, I> {
+        WordContractIter {
+            iter: self.rebuild(|ranges| ContractedRanges {
+                iter: ranges,
+                text,
+                skip_empty: true,
+            }),
+        }
+    }
+}
+
+impl<'a, I> Iterator for WordContractIter<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+impl<'a, I> FusedIterator for WordContractIter<'a, I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn expand_to_word_boundaries() {
+        let text = "Foo12 Bar34, Baz56";
+
+        let actual = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()))
+            .every_range(text.len())
+            .expand_to_word_boundaries(text)
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Included, "Foo12"),
+                (Excluded, " "),
+                (Included, "Bar34"),
+                (Excluded, ", "),
+                (Included, "Baz56"),
+            ]
+        );
+    }
+
+    #[test]
+    fn expand_to_word_boundaries_unicode() {
+        let text = "Føø Bår";
+
+        let actual = [3..5, 7..9]
+            .iter()
+            .cloned()
+            .every_range(text.len())
+            .expand_to_word_boundaries(text)
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [(Included, "Føø"), (Excluded, " "), (Included, "Bår")]
+        );
+    }
+
+    #[test]
+    fn expand_to_word_boundaries_after_next() {
+        let text = "Foo Bar";
+
+        let mut iter = [1..2, 5..6].iter().cloned().every_range(text.len());
+        assert_eq!(iter.next(), Some((Excluded, 0..1)));
+
+        let actual = iter.expand_to_word_boundaries(text).collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [(Included, 1..3), (Excluded, 3..4), (Included, 4..7)]
+        );
+    }
+
+    #[test]
+    fn contract_to_word_boundaries() {
+        let text = "Foo12 Bar34, Baz56";
+
+        let actual = [1..11, 12..14, 15..16]
+            .iter()
+            .cloned()
+            .every_range(text.len())
+            .contract_to_word_boundaries(text)
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Excluded, "Foo12 "),
+                (Included, "Bar34"),
+                (Excluded, ", Baz56")
+            ]
+        );
+    }
+
+    #[test]
+    fn contract_to_word_boundaries_keep_empty() {
+        let text = "Foo Bar";
+
+        let actual = [1..5, 5..7]
+            .iter()
+            .cloned()
+            .every_range(text.len())
+            .contract_to_word_boundaries(text)
+            .skip_empty(false)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Excluded, 0..4),
+                (Included, 4..4),
+                (Excluded, 4..7),
+                (Included, 7..7),
+            ]
+        );
+    }
+
+    #[test]
+    fn contract_to_word_boundaries_whole_words() {
+        let text = "Foo Bar";
+
+        let ranges = [0..3, 4..7];
+
+        let actual = ranges
+            .iter()
+            .cloned()
+            .every_range(text.len())
+            .contract_to_word_boundaries(text)
+            .collect::<Vec<_>>();
+        let expected = ranges
+            .iter()
+            .cloned()
+            .every_range(text.len())
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn expand_to_word_boundaries_skip_trailing() {
+        let text = "Foo Bar Baz";
+
+        let actual = std::iter::once(5..6)
+            .every_range(text.len())
+            .skip_trailing()
+            .expand_to_word_boundaries(text)
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, [(Excluded, "Foo "), (Included, "Bar")]);
+    }
+
+    #[test]
+    fn contract_to_word_boundaries_skip_trailing() {
+        let text = "Foo Bar Baz";
+
+        let actual = std::iter::once(1..10)
+            .every_range(text.len())
+            .skip_trailing()
+            .contract_to_word_boundaries(text)
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, [(Excluded, "Foo "), (Included, "Bar")]);
+    }
+
+    #[test]
+    fn expand_to_word_boundaries_consumed_from_back() {
+        let text = "Foo Bar Baz";
+
+        let mut iter = vec![5..6, 9..10].into_iter().every_range(text.len());
+        assert_eq!(iter.next_back(), Some((Excluded, 10..11)));
+        assert_eq!(iter.next_back(), Some((Included, 9..10)));
+
+        let actual = iter
+            .expand_to_word_boundaries(text)
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [(Excluded, "Foo "), (Included, "Bar"), (Excluded, " B")]
+        );
+    }
+
+    #[test]
+    fn contract_to_word_boundaries_consumed_from_back() {
+        let text = "Foo Bar Baz";
+
+        let mut iter = vec![0..3, 4..7, 8..11].into_iter().every_range(text.len());
+        assert_eq!(iter.next_back(), Some((Included, 8..11)));
+
+        let actual = iter
+            .contract_to_word_boundaries(text)
+            .map(|(kind, range)| (kind, &text[range]))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            [
+                (Included, "Foo"),
+                (Excluded, " "),
+                (Included, "Bar"),
+                (Excluded, " ")
+            ]
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::ops::Range;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+impl<I> EveryRangeIter<I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    /// Consume the `EveryRangeIter` and write `source` into `writer`,
+    /// while patching [`Included`] ranges found in `replacements`.
+    ///
+    /// [`Included`] ranges are looked up in `replacements`, if found
+    /// then the replacement bytes are written, otherwise the original
+    /// bytes of `source` are written. [`Excluded`] ranges always write
+    /// the original bytes of `source`.
+    ///
+    /// [`Included`]: enum.EveryRangeKind.html#variant.Included
+    /// [`Excluded`]: enum.EveryRangeKind.html#variant.Excluded
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use every_range::EveryRange;
+    ///
+    /// let source = b"Foo Bar Baz";
+    ///
+    /// let mut replacements = HashMap::new();
+    /// replacements.insert(4..7, &b"Qux"[..]);
+    ///
+    /// let mut output = Vec::new();
+    /// vec![0..3, 4..7]
+    ///     .into_iter()
+    ///     .every_range(source.len())
+    ///     .into_writer_with_replacements(source, &replacements, &mut output)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(output, b"Foo Qux Baz");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if any range is out of bounds of `source`.
+    pub fn into_writer_with_replacements<W: Write>(
+        self,
+        source: &[u8],
+        replacements: &HashMap<Range<usize>, &[u8]>,
+        writer: &mut W,
+    ) -> io::Result<()> {
+        for (kind, range) in self {
+            let bytes = match kind {
+                EveryRangeKind::Included => match replacements.get(&range) {
+                    Some(replacement) => replacement,
+                    None => &source[range],
+                },
+                EveryRangeKind::Excluded => &source[range],
+            };
+
+            writer.write_all(bytes)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EveryRange;
+
+    #[test]
+    fn into_writer_with_replacements() {
+        let source = b"Foo12Bar34Baz56";
+
+        let mut replacements = HashMap::new();
+        replacements.insert(3..5, &b"[a]"[..]);
+        replacements.insert(13..15, &b""[..]);
+
+        let mut output = Vec::new();
+        vec![3..5, 8..10, 13..15]
+            .into_iter()
+            .every_range(source.len())
+            .into_writer_with_replacements(source, &replacements, &mut output)
+            .unwrap();
+
+        assert_eq!(output, b"Foo[a]Bar34Baz");
+    }
+
+    #[test]
+    fn into_writer_without_replacements() {
+        let source = b"Foo12Bar34Baz56";
+
+        let mut output = Vec::new();
+        vec![3..5, 8..10, 13..15]
+            .into_iter()
+            .every_range(source.len())
+            .into_writer_with_replacements(source, &HashMap::new(), &mut output)
+            .unwrap();
+
+        assert_eq!(output, source);
+    }
+}
@@ -0,0 +1,123 @@
+use std::fmt;
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+use crate::{EveryRangeIter, EveryRangeKind};
+
+/// `ZipStrIter` is an [`EveryRangeIter`] over a string, which yields
+/// the substring of each range along with the range.
+///
+/// This `struct` is created by the [`every_range_zip_str`]
+/// method on [`EveryRange`].
+///
+/// [`every_range_zip_str`]: trait.EveryRange.html#method.every_range_zip_str
+/// [`EveryRange`]: trait.EveryRange.html
+/// [`EveryRangeIter`]: struct.EveryRangeIter.html
+pub struct ZipStrIter<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    iter: EveryRangeIter<I>,
+    s: &'a str,
+}
+
+impl<'a, I> fmt::Debug for ZipStrIter<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ZipStrIter")
+            .field("iter", &self.iter)
+            .field("s", &self.s)
+            .finish()
+    }
+}
+
+impl<'a, I> ZipStrIter<'a, I>
+where
+    I: Clone + Iterator<Item = Range<usize>>,
+{
+    pub(crate) fn new(ranges: I, s: &'a str) -> Self {
+        for range in ranges.clone() {
+            assert!(
+                (range.start <= range.end) && (range.end <= s.len()),
+                "range {:?} is out of bounds of string of length {}",
+                range,
+                s.len()
+            );
+            assert!(
+                s.is_char_boundary(range.start) && s.is_char_boundary(range.end),
+                "range {:?} is not on char boundaries",
+                range
+            );
+        }
+
+        Self {
+            iter: EveryRangeIter::new(ranges, s.len()),
+            s,
+        }
+    }
+}
+
+impl<'a, I> Iterator for ZipStrIter<'a, I>
+where
+    I: Iterator<Item = Range<usize>>,
+{
+    type Item = (EveryRangeKind, Range<usize>, &'a str);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (kind, range) = self.iter.next()?;
+        let s = &self.s[range.clone()];
+        Some((kind, range, s))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, I> FusedIterator for ZipStrIter<'a, I> where I: Iterator<Item = Range<usize>> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::EveryRange;
+    use crate::EveryRangeKind::*;
+
+    #[test]
+    fn every_range_zip_str() {
+        let text = "Foo12Bar34Baz56";
+
+        let ranges = text
+            .match_indices(char::is_numeric)
+            .map(|(start, part)| start..(start + part.len()));
+
+        let mut digits = String::new();
+        for (kind, range, s) in ranges.every_range_zip_str(text) {
+            assert_eq!(s, &text[range.clone()]);
+
+            match (kind, range, s) {
+                (Included, _, s) => digits.push_str(s),
+                (Excluded, range, s) => assert_eq!(s.len(), range.len()),
+            }
+        }
+
+        assert_eq!(digits, "123456");
+    }
+
+    #[test]
+    #[should_panic = "range 4..16 is out of bounds of string of length 15"]
+    fn every_range_zip_str_out_of_bounds() {
+        let text = "Foo12Bar34Baz56";
+
+        // Panics before iterating
+        let _ = [0..2, 4..16].iter().cloned().every_range_zip_str(text);
+    }
+
+    #[test]
+    #[should_panic = "range 1..2 is not on char boundaries"]
+    fn every_range_zip_str_char_boundary() {
+        let _ = std::iter::once(1..2).every_range_zip_str("Føø");
+    }
+}